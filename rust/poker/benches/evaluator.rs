@@ -0,0 +1,46 @@
+//! Compares `HandScore::from` with `HandStrength::from`.
+//!
+//! Run with `cargo bench`.
+
+use poker::*;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const HANDS: usize = 1_000_000;
+
+/// Seeded hands, so every run times the same work.
+fn hands() -> Vec<Hand> {
+    (0..HANDS as u64)
+        .map(|seed| Deck::shuffled(seed).deal_hand().unwrap())
+        .collect()
+}
+
+fn time<T>(name: &str, hands: &[Hand], f: impl Fn(Hand) -> T) -> Duration {
+    let start = Instant::now();
+    for h in hands {
+        black_box(f(black_box(*h)));
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<12} {:>8.1} ns/hand",
+        name,
+        elapsed.as_nanos() as f64 / hands.len() as f64
+    );
+    elapsed
+}
+
+fn main() {
+    let hands = hands();
+
+    // Builds the lookup tables outside of the timed loop.
+    black_box(HandStrength::from(hands[0]));
+
+    let score = time("HandScore", &hands, HandScore::from);
+    let strength = time("HandStrength", &hands, HandStrength::from);
+    println!(
+        "HandStrength is {:.1}x faster",
+        score.as_secs_f64() / strength.as_secs_f64()
+    );
+}
@@ -0,0 +1,130 @@
+use crate::{rng::SplitMix64, Card, Hand, Rank, Suit, Undecodable};
+
+/// The cards left to deal, top card first.
+///
+/// ```
+/// # use poker::*;
+/// let mut deck = Deck::shuffled(42);
+/// assert_eq!(deck.len(), 52);
+///
+/// let hand = deck.deal_hand().unwrap();
+/// deck.burn().unwrap();
+/// let flop = deck.deal(3).unwrap();
+/// assert_eq!(deck.len(), 52 - 5 - 1 - 3);
+/// assert!(flop.iter().all(|c| !hand.cards.contains(c)));
+///
+/// // The same seed deals the same cards.
+/// assert_eq!(Deck::shuffled(42).deal_hand(), Ok(hand));
+/// assert_ne!(Deck::shuffled(43).deal_hand(), Ok(hand));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// All 52 cards, in order.
+    ///
+    /// ```
+    /// # use poker::*;
+    /// let deck = Deck::new();
+    /// assert_eq!(deck.cards()[0], Card::try_from("2C").unwrap());
+    /// assert_eq!(deck.cards()[51], Card::try_from("AS").unwrap());
+    /// ```
+    pub fn new() -> Self {
+        use Rank::*;
+        use Suit::*;
+
+        let ranks = [
+            Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Jack, Queen, King, Ace,
+        ];
+        Deck {
+            cards: ranks
+                .iter()
+                .flat_map(|rank| {
+                    [Clubs, Diamonds, Hearts, Spades].map(|suit| Card { rank: *rank, suit })
+                })
+                .collect(),
+        }
+    }
+
+    pub fn shuffled(seed: u64) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle(seed);
+        deck
+    }
+
+    pub fn shuffle(&mut self, seed: u64) {
+        let n = self.cards.len();
+        SplitMix64::new(seed).partial_shuffle(&mut self.cards, n);
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Takes `n` cards off the top.
+    ///
+    /// ```
+    /// # use poker::*;
+    /// let mut deck = Deck::new();
+    /// assert_eq!(deck.deal(2), Ok(parse_cards("2C 2D").unwrap()));
+    /// assert!(deck.deal(51).is_err());
+    /// assert_eq!(deck.len(), 50);
+    /// ```
+    pub fn deal(&mut self, n: usize) -> Result<Vec<Card>, Undecodable> {
+        if n > self.cards.len() {
+            return Err(Undecodable(format!(
+                "{}: can't deal more than the {} cards left",
+                n,
+                self.cards.len()
+            )));
+        }
+
+        Ok(self.cards.drain(..n).collect())
+    }
+
+    pub fn deal_hand(&mut self) -> Result<Hand, Undecodable> {
+        Hand::try_from(&self.deal(5)?[..])
+    }
+
+    /// Discards the top card.
+    pub fn burn(&mut self) -> Result<(), Undecodable> {
+        self.deal(1).map(|_| ())
+    }
+
+    /// Takes cards that are already known to be out of the deck, such as
+    /// someone's hole cards, wherever they are.
+    ///
+    /// ```
+    /// # use poker::*;
+    /// let mut deck = Deck::shuffled(1);
+    /// let known = parse_cards("AS KD").unwrap();
+    /// deck.remove(&known).unwrap();
+    /// assert_eq!(deck.len(), 50);
+    /// assert!(deck.cards().iter().all(|c| !known.contains(c)));
+    /// assert!(deck.remove(&known[..1]).is_err());
+    /// ```
+    pub fn remove(&mut self, cards: &[Card]) -> Result<(), Undecodable> {
+        if let Some(c) = cards.iter().find(|c| !self.cards.contains(c)) {
+            return Err(Undecodable(format!("{}: not in the deck", c)));
+        }
+
+        self.cards.retain(|c| !cards.contains(c));
+        Ok(())
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
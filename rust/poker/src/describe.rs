@@ -0,0 +1,302 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{HandScore, Rank};
+
+impl Rank {
+    /// ```
+    /// # use poker::Rank;
+    /// assert_eq!(Rank::Six.name(), "Six");
+    /// assert_eq!(Rank::AceLow.name(), "Ace");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::AceLow | Rank::Ace => "Ace",
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+        }
+    }
+
+    /// ```
+    /// # use poker::Rank;
+    /// assert_eq!(Rank::Six.plural(), "Sixes");
+    /// assert_eq!(Rank::King.plural(), "Kings");
+    /// ```
+    pub fn plural(&self) -> String {
+        match self {
+            Rank::Six => "Sixes".to_string(),
+            _ => format!("{}s", self.name()),
+        }
+    }
+}
+
+impl fmt::Display for HandScore {
+    /// ```
+    /// # use poker::*;
+    /// fn describe(s: &str) -> String {
+    ///     HandScore::from(s).to_string()
+    /// }
+    ///
+    /// assert_eq!(describe("2C 4D 6H 8S KC"), "High card, King");
+    /// assert_eq!(describe("JC JD 6H 8S KC"), "One pair, Jacks");
+    /// assert_eq!(describe("KC KD 9H 9S 2C"), "Two pair, Kings and Nines");
+    /// assert_eq!(describe("6C 6D 6H 8S KC"), "Three of a kind, Sixes");
+    /// assert_eq!(describe("AC 2D 3H 4S 5C"), "Straight, Five high");
+    /// assert_eq!(describe("2C 4C 6C 8C AC"), "Flush, Ace high");
+    /// assert_eq!(describe("KC KD KH 9S 9C"), "Full house, Kings over Nines");
+    /// assert_eq!(describe("AC AD AH AS 9C"), "Four of a kind, Aces");
+    /// assert_eq!(describe("9H 10H JH QH KH"), "Straight flush, King high");
+    /// assert_eq!(describe("10H JH QH KH AH"), "Royal flush");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandScore::HighCard { ranks } => write!(f, "High card, {}", ranks[0].name()),
+            HandScore::OnePair { top_rank, .. } => write!(f, "One pair, {}", top_rank.plural()),
+            HandScore::TwoPair {
+                top_rank,
+                second_rank,
+                ..
+            } => write!(
+                f,
+                "Two pair, {} and {}",
+                top_rank.plural(),
+                second_rank.plural()
+            ),
+            HandScore::ThreeOfAKind { top_rank, .. } => {
+                write!(f, "Three of a kind, {}", top_rank.plural())
+            }
+            HandScore::Straight { top_rank } => write!(f, "Straight, {} high", top_rank.name()),
+            HandScore::Flush { ranks } => write!(f, "Flush, {} high", ranks[0].name()),
+            HandScore::FullHouse {
+                top_rank,
+                bottom_rank,
+            } => write!(
+                f,
+                "Full house, {} over {}",
+                top_rank.plural(),
+                bottom_rank.plural()
+            ),
+            HandScore::FourOfAKind { top_rank, .. } => {
+                write!(f, "Four of a kind, {}", top_rank.plural())
+            }
+            HandScore::StraightFlush {
+                top_rank: Rank::Ace,
+            } => write!(f, "Royal flush"),
+            HandScore::StraightFlush { top_rank } => {
+                write!(f, "Straight flush, {} high", top_rank.name())
+            }
+        }
+    }
+}
+
+/// One of the ranks in a `HandScore` variant, named after its field.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ScoreField {
+    TopRank,
+    SecondRank,
+    BottomRank,
+    OtherRank,
+    OtherRanks(usize),
+    Ranks(usize),
+}
+
+/// What settled a comparison between two `HandScore`s.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Decider {
+    /// They're different kinds of hand.
+    Variant,
+    /// They're the same kind of hand, and `field` is the first rank that
+    /// differs between them.
+    Rank {
+        field: ScoreField,
+        ours: Rank,
+        theirs: Rank,
+    },
+    /// They're equal.
+    Tie,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Comparison {
+    pub ordering: Ordering,
+    pub decider: Decider,
+}
+
+fn ordinal(i: usize) -> &'static str {
+    ["first", "second", "third", "fourth", "fifth"][i]
+}
+
+impl HandScore {
+    /// The ranks this variant is compared on, in the order they're compared.
+    fn fields(&self) -> Vec<(ScoreField, Rank)> {
+        use ScoreField::*;
+
+        match self {
+            HandScore::HighCard { ranks } | HandScore::Flush { ranks } => ranks
+                .iter()
+                .enumerate()
+                .map(|(i, r)| (Ranks(i), *r))
+                .collect(),
+            HandScore::OnePair {
+                top_rank,
+                other_ranks,
+            } => [(TopRank, *top_rank)]
+                .into_iter()
+                .chain(
+                    other_ranks
+                        .iter()
+                        .enumerate()
+                        .map(|(i, r)| (OtherRanks(i), *r)),
+                )
+                .collect(),
+            HandScore::ThreeOfAKind {
+                top_rank,
+                other_ranks,
+            } => [(TopRank, *top_rank)]
+                .into_iter()
+                .chain(
+                    other_ranks
+                        .iter()
+                        .enumerate()
+                        .map(|(i, r)| (OtherRanks(i), *r)),
+                )
+                .collect(),
+            HandScore::TwoPair {
+                top_rank,
+                second_rank,
+                other_rank,
+            } => vec![
+                (TopRank, *top_rank),
+                (SecondRank, *second_rank),
+                (OtherRank, *other_rank),
+            ],
+            HandScore::Straight { top_rank } | HandScore::StraightFlush { top_rank } => {
+                vec![(TopRank, *top_rank)]
+            }
+            HandScore::FullHouse {
+                top_rank,
+                bottom_rank,
+            }
+            | HandScore::FourOfAKind {
+                top_rank,
+                bottom_rank,
+            } => vec![(TopRank, *top_rank), (BottomRank, *bottom_rank)],
+        }
+    }
+
+    /// What a field means in this variant, e.g. "the second kicker".
+    fn field_name(&self, field: ScoreField) -> String {
+        use ScoreField::*;
+
+        match (self, field) {
+            (_, Ranks(0)) => "the highest card".to_string(),
+            (_, Ranks(i)) => format!("the {} highest card", ordinal(i)),
+            (HandScore::OnePair { .. }, TopRank) | (HandScore::FullHouse { .. }, BottomRank) => {
+                "the pair".to_string()
+            }
+            (HandScore::TwoPair { .. }, TopRank) => "the top pair".to_string(),
+            (_, SecondRank) => "the bottom pair".to_string(),
+            (HandScore::ThreeOfAKind { .. } | HandScore::FullHouse { .. }, TopRank) => {
+                "the three of a kind".to_string()
+            }
+            (HandScore::FourOfAKind { .. }, TopRank) => "the four of a kind".to_string(),
+            (_, TopRank) => "the highest card".to_string(),
+            (_, OtherRanks(i)) => format!("the {} kicker", ordinal(i)),
+            (_, OtherRank | BottomRank) => "the kicker".to_string(),
+        }
+    }
+
+    /// Compares like `Ord` does, but also says what settled it.
+    ///
+    /// ```
+    /// # use poker::{*, Rank::*};
+    /// let kings = HandScore::from("KC KD QH 8S 2C");
+    /// assert_eq!(
+    ///     kings.compare(&HandScore::from("KH KS QD 7S 3C")),
+    ///     Comparison {
+    ///         ordering: std::cmp::Ordering::Greater,
+    ///         decider: Decider::Rank { field: ScoreField::OtherRanks(1), ours: Eight, theirs: Seven },
+    ///     },
+    /// );
+    /// assert_eq!(kings.compare(&HandScore::from("AC 2D 3H 4S 5C")).decider, Decider::Variant);
+    /// assert_eq!(kings.compare(&HandScore::from("KH KS QD 8C 2D")).decider, Decider::Tie);
+    /// ```
+    pub fn compare(&self, other: &HandScore) -> Comparison {
+        let ordering = self.cmp(other);
+        let decider = if self.variant() != other.variant() {
+            Decider::Variant
+        } else {
+            self.fields()
+                .into_iter()
+                .zip(other.fields())
+                .find(|((_, ours), (_, theirs))| ours != theirs)
+                .map_or(Decider::Tie, |((field, ours), (_, theirs))| Decider::Rank {
+                    field,
+                    ours,
+                    theirs,
+                })
+        };
+
+        Comparison { ordering, decider }
+    }
+
+    /// Explains how this hand does against `other`.
+    ///
+    /// ```
+    /// # use poker::*;
+    /// fn explain(a: &str, b: &str) -> String {
+    ///     HandScore::from(a).explain(&HandScore::from(b))
+    /// }
+    ///
+    /// assert_eq!(
+    ///     explain("KC KD KH 9S 9C", "2C 4C 6C 8C AC"),
+    ///     "Full house, Kings over Nines, beats Flush, Ace high",
+    /// );
+    /// assert_eq!(
+    ///     explain("KC KD QH 7S 2C", "KH KS QD 8S 3C"),
+    ///     "One pair, Kings, loses to One pair, Kings, on the second kicker: Seven against Eight",
+    /// );
+    /// assert_eq!(
+    ///     explain("KC KD KH 9S 9C", "QC QD QH 9H 9D"),
+    ///     "Full house, Kings over Nines, beats Full house, Queens over Nines, on the three of a kind: King against Queen",
+    /// );
+    /// assert_eq!(
+    ///     explain("2C 4D 6H 8S KC", "2D 4H 6S 8C KD"),
+    ///     "High card, King, ties High card, King",
+    /// );
+    /// ```
+    pub fn explain(&self, other: &HandScore) -> String {
+        let Comparison { ordering, decider } = self.compare(other);
+        let verb = match ordering {
+            Ordering::Greater => "beats",
+            Ordering::Less => "loses to",
+            Ordering::Equal => "ties",
+        };
+
+        match decider {
+            Decider::Rank {
+                field,
+                ours,
+                theirs,
+            } => format!(
+                "{}, {} {}, on {}: {} against {}",
+                self,
+                verb,
+                other,
+                self.field_name(field),
+                ours.name(),
+                theirs.name()
+            ),
+            Decider::Variant | Decider::Tie => format!("{}, {} {}", self, verb, other),
+        }
+    }
+}
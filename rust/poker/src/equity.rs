@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+
+use crate::{holdem::best_strength, rng::SplitMix64, Card, Deck, Undecodable};
+
+/// How `equity` deals the cards nobody knows yet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EquityConfig {
+    /// Deal every possible way when there are at most this many.
+    pub exhaustive_limit: u64,
+    /// Otherwise, deal this many random ways.
+    pub trials: u64,
+    pub seed: u64,
+}
+
+impl Default for EquityConfig {
+    fn default() -> Self {
+        EquityConfig {
+            exhaustive_limit: 100_000,
+            trials: 100_000,
+            seed: 0,
+        }
+    }
+}
+
+/// How one player did over all the deals. `win`, `tie` and `loss` add up to
+/// one; `share` is the part of the pot they can expect, counting split pots.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlayerEquity {
+    pub win: f64,
+    pub tie: f64,
+    pub loss: f64,
+    pub share: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquityReport {
+    /// In the same order as the holdings.
+    pub players: Vec<PlayerEquity>,
+    pub deals: u64,
+    /// Whether `deals` was every possible deal, rather than a random sample.
+    pub exhaustive: bool,
+}
+
+/// How many ways there are of dealing `sizes` cards, in turn, out of
+/// `available`, saturating at `u64::MAX`.
+fn deal_count(available: usize, sizes: &[usize]) -> u64 {
+    let mut count: u64 = 1;
+    let mut available = available as u64;
+    for size in sizes {
+        for i in 0..*size as u64 {
+            // Multiplying before dividing keeps every step a whole number.
+            count = count.saturating_mul(available - i) / (i + 1);
+        }
+        available -= *size as u64;
+    }
+    count
+}
+
+/// Calls `f` with every way of dealing `sizes` cards, in turn, out of the
+/// unused `available` ones. Order within a group doesn't matter, so each group
+/// is only dealt in increasing index order.
+fn deal_every_way(
+    available: &[Card],
+    used: &mut [bool],
+    sizes: &mut [usize],
+    start: usize,
+    dealt: &mut Vec<Card>,
+    f: &mut impl FnMut(&[Card]),
+) {
+    match sizes.first() {
+        None => f(dealt),
+        Some(0) => deal_every_way(available, used, &mut sizes[1..], 0, dealt, f),
+        Some(_) => {
+            for i in start..available.len() {
+                if used[i] {
+                    continue;
+                }
+                used[i] = true;
+                dealt.push(available[i]);
+                sizes[0] -= 1;
+                deal_every_way(available, used, sizes, i + 1, dealt, f);
+                sizes[0] += 1;
+                dealt.pop();
+                used[i] = false;
+            }
+        }
+    }
+}
+
+/// Each player's chances of winning a hold'em hand, given the hole cards we
+/// know (up to 2 per player) and the board so far (up to 5 cards).
+///
+/// The missing cards are dealt every possible way if there are at most
+/// `config.exhaustive_limit` ways, otherwise `config.trials` random ways,
+/// seeded by `config.seed`.
+///
+/// ```
+/// # use poker::*;
+/// let holdings = [parse_cards("AS AH").unwrap(), parse_cards("KS KH").unwrap()];
+/// let board = parse_cards("2C 7D 9H").unwrap();
+/// let report = equity(&holdings, &board, &EquityConfig::default()).unwrap();
+/// assert!(report.exhaustive);
+/// assert_eq!(report.deals, 990);
+/// assert!(report.players[0].win > 0.9);
+/// assert_eq!(report.players[0].win, report.players[1].loss);
+/// assert_eq!(report.players[0].tie, 0.0);
+///
+/// // Both play the board.
+/// let board = parse_cards("10D JD QD KD AD").unwrap();
+/// let report = equity(&holdings, &board, &EquityConfig::default()).unwrap();
+/// assert_eq!(report.deals, 1);
+/// assert_eq!(report.players[1], PlayerEquity { win: 0.0, tie: 1.0, loss: 0.0, share: 0.5 });
+///
+/// // Too many ways to deal the whole board: sample them instead.
+/// let holdings = [parse_cards("AS AH").unwrap(), parse_cards("7C").unwrap(), vec![]];
+/// let config = EquityConfig { trials: 2_000, seed: 7, ..EquityConfig::default() };
+/// let report = equity(&holdings, &[], &config).unwrap();
+/// assert!(!report.exhaustive);
+/// assert_eq!(report.deals, 2_000);
+/// assert!(report.players[0].share > report.players[1].share);
+/// assert_eq!(report, equity(&holdings, &[], &config).unwrap());
+///
+/// assert!(equity(&[parse_cards("AS AH AD").unwrap()], &[], &config).is_err());
+/// assert!(equity(&holdings, &parse_cards("AS").unwrap(), &config).is_err());
+/// ```
+pub fn equity(
+    holdings: &[Vec<Card>],
+    board: &[Card],
+    config: &EquityConfig,
+) -> Result<EquityReport, Undecodable> {
+    if let Some(h) = holdings.iter().find(|h| h.len() > 2) {
+        return Err(Undecodable(format!(
+            "{:?}: expected at most 2 hole cards",
+            h
+        )));
+    }
+
+    if config.trials == 0 {
+        return Err(Undecodable(format!(
+            "{:?}: expected at least one trial",
+            config
+        )));
+    }
+
+    if board.len() > 5 {
+        return Err(Undecodable(format!(
+            "{:?}: expected the board to be at most 5 cards",
+            board
+        )));
+    }
+
+    let known: Vec<Card> = holdings.iter().flatten().chain(board).copied().collect();
+    if known.iter().collect::<HashSet<_>>().len() != known.len() {
+        return Err(Undecodable(format!(
+            "{:?}: expected all cards to be different",
+            known
+        )));
+    }
+
+    let mut deck = Deck::new();
+    deck.remove(&known)?;
+    let mut available = deck.cards().to_vec();
+
+    // Missing board cards first, then each player's missing hole cards.
+    let board_missing = 5 - board.len();
+    let mut sizes: Vec<usize> = [board_missing]
+        .into_iter()
+        .chain(holdings.iter().map(|h| 2 - h.len()))
+        .collect();
+    let missing: usize = sizes.iter().sum();
+    if missing > available.len() {
+        return Err(Undecodable(format!(
+            "{} players: not enough cards to deal",
+            holdings.len()
+        )));
+    }
+
+    let mut wins = vec![0u64; holdings.len()];
+    let mut ties = vec![0u64; holdings.len()];
+    let mut shares = vec![0f64; holdings.len()];
+    let mut seven = vec![];
+    let mut strengths = vec![];
+    let mut showdown = |dealt: &[Card]| {
+        let (board_rest, mut holes_rest) = dealt.split_at(board_missing);
+        strengths.clear();
+        for h in holdings {
+            let (hole_rest, rest) = holes_rest.split_at(2 - h.len());
+            holes_rest = rest;
+            seven.clear();
+            seven.extend(h.iter().chain(hole_rest).chain(board).chain(board_rest));
+            strengths.push(best_strength(&seven));
+        }
+
+        let best = strengths.iter().max().copied();
+        let winners = strengths.iter().filter(|s| Some(**s) == best).count();
+        for (i, s) in strengths.iter().enumerate() {
+            if Some(*s) == best {
+                if winners == 1 {
+                    wins[i] += 1;
+                } else {
+                    ties[i] += 1;
+                }
+                shares[i] += 1.0 / winners as f64;
+            }
+        }
+    };
+
+    let count = deal_count(available.len(), &sizes);
+    let exhaustive = count <= config.exhaustive_limit;
+    let deals = if exhaustive {
+        let mut used = vec![false; available.len()];
+        deal_every_way(
+            &available,
+            &mut used,
+            &mut sizes,
+            0,
+            &mut vec![],
+            &mut showdown,
+        );
+        count
+    } else {
+        let mut rng = SplitMix64::new(config.seed);
+        for _ in 0..config.trials {
+            rng.partial_shuffle(&mut available, missing);
+            showdown(&available[..missing]);
+        }
+        config.trials
+    };
+
+    let players = (0..holdings.len())
+        .map(|i| {
+            let losses = deals - wins[i] - ties[i];
+            PlayerEquity {
+                win: wins[i] as f64 / deals as f64,
+                tie: ties[i] as f64 / deals as f64,
+                loss: losses as f64 / deals as f64,
+                share: shares[i] / deals as f64,
+            }
+        })
+        .collect();
+
+    Ok(EquityReport {
+        players,
+        deals,
+        exhaustive,
+    })
+}
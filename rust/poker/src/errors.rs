@@ -0,0 +1,323 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::{highest_scoring, Card, Hand, HandScore, Rank, Suit};
+
+/// What's wrong with one of the hands given to `try_winning_hands`. `hand` is
+/// the hand's index, and `offset` the byte offset in it where the problem is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HandError {
+    /// A card that's too short to have both a rank and a suit.
+    BadCard {
+        hand: usize,
+        offset: usize,
+        card: String,
+    },
+    BadRank {
+        hand: usize,
+        offset: usize,
+        rank: String,
+    },
+    BadSuit {
+        hand: usize,
+        offset: usize,
+        suit: char,
+    },
+    /// `offset` is where the sixth card starts, or the end of a short hand.
+    WrongCardCount {
+        hand: usize,
+        offset: usize,
+        count: usize,
+    },
+    /// `offset` is where the card shows up again, after `first_offset`.
+    DuplicateCard {
+        hand: usize,
+        offset: usize,
+        card: Card,
+        first_offset: usize,
+    },
+    /// `card` is in this hand, at `offset`, and also in an earlier hand.
+    SharedCard {
+        hand: usize,
+        offset: usize,
+        card: Card,
+        first_hand: usize,
+        first_offset: usize,
+    },
+}
+
+impl HandError {
+    pub fn hand(&self) -> usize {
+        match self {
+            HandError::BadCard { hand, .. }
+            | HandError::BadRank { hand, .. }
+            | HandError::BadSuit { hand, .. }
+            | HandError::WrongCardCount { hand, .. }
+            | HandError::DuplicateCard { hand, .. }
+            | HandError::SharedCard { hand, .. } => *hand,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        match self {
+            HandError::BadCard { offset, .. }
+            | HandError::BadRank { offset, .. }
+            | HandError::BadSuit { offset, .. }
+            | HandError::WrongCardCount { offset, .. }
+            | HandError::DuplicateCard { offset, .. }
+            | HandError::SharedCard { offset, .. } => *offset,
+        }
+    }
+}
+
+impl fmt::Display for HandError {
+    /// ```
+    /// # use poker::*;
+    /// let e = try_winning_hands(&["2C 3C 4C 5C 6C", "2D 3D 4D 5D 1D"]).unwrap_err();
+    /// assert_eq!(e.to_string(), "hand 1, byte 12: \"1\": not a Rank");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hand {}, byte {}: ", self.hand(), self.offset())?;
+        match self {
+            HandError::BadCard { card, .. } => {
+                write!(f, "{:?}: expected to be at least two chars", card)
+            }
+            HandError::BadRank { rank, .. } => write!(f, "{:?}: not a Rank", rank),
+            HandError::BadSuit { suit, .. } => write!(f, "{:?}: not a Suit", suit),
+            HandError::WrongCardCount { count, .. } => {
+                write!(f, "expected to be 5 cards, not {}", count)
+            }
+            HandError::DuplicateCard {
+                card, first_offset, ..
+            } => write!(f, "{} is already at byte {}", card, first_offset),
+            HandError::SharedCard {
+                card,
+                first_hand,
+                first_offset,
+                ..
+            } => write!(
+                f,
+                "{} is already in hand {}, byte {}",
+                card, first_hand, first_offset
+            ),
+        }
+    }
+}
+
+impl Error for HandError {}
+
+/// The cards in `s`, with the byte offset each one starts at.
+pub(crate) fn card_offsets(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_ascii_whitespace()
+        .map(move |c| (c.as_ptr() as usize - s.as_ptr() as usize, c))
+}
+
+fn parse_card(hand: usize, offset: usize, s: &str) -> Result<Card, HandError> {
+    let Some((suit_offset, suit)) = s.char_indices().last().filter(|(i, _)| *i > 0) else {
+        return Err(HandError::BadCard {
+            hand,
+            offset,
+            card: s.to_string(),
+        });
+    };
+
+    let rank = &s[..suit_offset];
+    Ok(Card {
+        rank: Rank::try_from(rank).map_err(|_| HandError::BadRank {
+            hand,
+            offset,
+            rank: rank.to_string(),
+        })?,
+        suit: Suit::try_from(&suit).map_err(|_| HandError::BadSuit {
+            hand,
+            offset: offset + suit_offset,
+            suit,
+        })?,
+    })
+}
+
+/// Like `Hand::try_from`, but saying exactly what's wrong, and where.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(parse_hand(0, "AS 2S 3S 4S 5S"), Ok(Hand::of("AS 2S 3S 4S 5S")));
+/// assert_eq!(
+///     parse_hand(3, "AS 2S 3X 4S 5S"),
+///     Err(HandError::BadSuit { hand: 3, offset: 7, suit: 'X' }),
+/// );
+/// assert_eq!(
+///     parse_hand(0, "AS 2S 3S 4S 5S 6S"),
+///     Err(HandError::WrongCardCount { hand: 0, offset: 15, count: 6 }),
+/// );
+/// assert_eq!(
+///     parse_hand(0, "AS 2S 3S"),
+///     Err(HandError::WrongCardCount { hand: 0, offset: 8, count: 3 }),
+/// );
+/// assert_eq!(
+///     parse_hand(0, "AS 2S AS 3S 4S"),
+///     Err(HandError::DuplicateCard {
+///         hand: 0,
+///         offset: 6,
+///         card: Card::try_from("AS").unwrap(),
+///         first_offset: 0,
+///     }),
+/// );
+/// assert!(matches!(parse_hand(0, "A 2S 3S 4S 5S"), Err(HandError::BadCard { offset: 0, .. })));
+/// assert!(matches!(parse_hand(0, "AS 2S 3S 4S 11S"), Err(HandError::BadRank { offset: 12, .. })));
+/// ```
+pub fn parse_hand(hand: usize, s: &str) -> Result<Hand, HandError> {
+    let mut cards = vec![];
+    let mut seen: HashMap<Card, usize> = HashMap::new();
+    for (offset, c) in card_offsets(s) {
+        let card = parse_card(hand, offset, c)?;
+        if let Some(first_offset) = seen.insert(card, offset) {
+            return Err(HandError::DuplicateCard {
+                hand,
+                offset,
+                card,
+                first_offset,
+            });
+        }
+        cards.push((offset, card));
+    }
+
+    match cards[..] {
+        [(_, a), (_, b), (_, c), (_, d), (_, e)] => Ok(Hand::from([a, b, c, d, e])),
+        _ => Err(HandError::WrongCardCount {
+            hand,
+            offset: cards.get(5).map_or(s.len(), |(offset, _)| *offset),
+            count: cards.len(),
+        }),
+    }
+}
+
+/// Like `winning_hands`, but returning the first malformed hand's error
+/// instead of panicking. Like `winning_hands`, it doesn't mind hands sharing
+/// cards; see `try_winning_round` for that.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(try_winning_hands(&["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H"]), Ok(vec!["4S 5S 7H 8D JC"]));
+/// assert_eq!(try_winning_hands(&[]), Ok(vec![]));
+/// assert_eq!(
+///     try_winning_hands(&["4S 5S 7H 8D JC", "2S 4C 7S 9H"]).map_err(|e| e.hand()),
+///     Err(1),
+/// );
+/// ```
+pub fn try_winning_hands<'a>(hands: &[&'a str]) -> Result<Vec<&'a str>, HandError> {
+    Ok(highest_scoring(
+        hands
+            .iter()
+            .enumerate()
+            .map(|(i, s)| Ok((HandScore::from(parse_hand(i, s)?), *s)))
+            .collect::<Result<_, HandError>>()?,
+    ))
+}
+
+/// A card that shows up more than once in a round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collision {
+    pub card: Card,
+    /// Every hand index and byte offset the card is at, in reading order.
+    pub places: Vec<(usize, usize)>,
+}
+
+/// Every card that's in the round more than once, whether twice in the same
+/// hand or in different hands, in the order they first show up. Cards that
+/// don't parse are skipped.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(collisions(&["2C 3C 4C 5C 6C", "2D 3D 4D 5D 6D"]), vec![]);
+/// assert_eq!(
+///     collisions(&["AS 2S AS 3S 4S", "KD QD 4S 2S JD", "?? 4S"]),
+///     vec![
+///         Collision { card: Card::try_from("AS").unwrap(), places: vec![(0, 0), (0, 6)] },
+///         Collision { card: Card::try_from("2S").unwrap(), places: vec![(0, 3), (1, 9)] },
+///         Collision { card: Card::try_from("4S").unwrap(), places: vec![(0, 12), (1, 6), (2, 3)] },
+///     ],
+/// );
+/// ```
+pub fn collisions(hands: &[&str]) -> Vec<Collision> {
+    let mut places: HashMap<Card, Vec<(usize, usize)>> = HashMap::new();
+    for (hand, s) in hands.iter().enumerate() {
+        for (offset, c) in card_offsets(s) {
+            if let Ok(card) = parse_card(hand, offset, c) {
+                places.entry(card).or_default().push((hand, offset));
+            }
+        }
+    }
+
+    let mut collisions: Vec<Collision> = places
+        .into_iter()
+        .filter(|(_, places)| places.len() > 1)
+        .map(|(card, places)| Collision { card, places })
+        .collect();
+    collisions.sort_by_key(|c| c.places[0]);
+    collisions
+}
+
+/// Parses a round of hands dealt out of the same deck, so no card can be in
+/// more than one of them.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(
+///     parse_round(&["2C 3C 4C 5C 6C", "2D 3D 4D 5D 6D"]),
+///     Ok(vec![Hand::of("2C 3C 4C 5C 6C"), Hand::of("2D 3D 4D 5D 6D")]),
+/// );
+/// assert_eq!(
+///     parse_round(&["2C 3C 4C 5C 6C", "2D 3D 4D 5D 6D", "AH KH 4D QH JH"]),
+///     Err(HandError::SharedCard {
+///         hand: 2,
+///         offset: 6,
+///         card: Card::try_from("4D").unwrap(),
+///         first_hand: 1,
+///         first_offset: 6,
+///     }),
+/// );
+/// ```
+pub fn parse_round(hands: &[&str]) -> Result<Vec<Hand>, HandError> {
+    let parsed = hands
+        .iter()
+        .enumerate()
+        .map(|(i, s)| parse_hand(i, s))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(Collision { card, places }) =
+        collisions(hands).into_iter().min_by_key(|c| c.places[1])
+    {
+        let [(first_hand, first_offset), (hand, offset), ..] = places[..] else {
+            unreachable!("a collision is in at least two places");
+        };
+        return Err(HandError::SharedCard {
+            hand,
+            offset,
+            card,
+            first_hand,
+            first_offset,
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Like `try_winning_hands`, but for hands dealt out of the same deck, which
+/// can't share any cards.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(try_winning_round(&["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H"]), Ok(vec!["4S 5S 7H 8D JC"]));
+/// assert!(matches!(
+///     try_winning_round(&["3S 4S 5D 6H JH", "3S 4S 5D 6H JH"]),
+///     Err(HandError::SharedCard { hand: 1, first_hand: 0, .. }),
+/// ));
+/// ```
+pub fn try_winning_round<'a>(hands: &[&'a str]) -> Result<Vec<&'a str>, HandError> {
+    Ok(highest_scoring(
+        parse_round(hands)?
+            .into_iter()
+            .zip(hands)
+            .map(|(h, s)| (HandScore::from(h), *s))
+            .collect(),
+    ))
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+
+use crate::{highest_scoring, Card, Hand, HandScore, HandStrength, Undecodable};
+
+/// ```
+/// # use poker::{*, Rank::*, Suit::*};
+/// assert_eq!(
+///     parse_cards("AS 10D"),
+///     Ok(vec![Card{rank: Ace, suit: Spades}, Card{rank: Ten, suit: Diamonds}]),
+/// );
+/// assert_eq!(parse_cards(""), Ok(vec![]));
+/// assert!(parse_cards("AS 1D").is_err());
+/// ```
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, Undecodable> {
+    s.split_ascii_whitespace().map(Card::try_from).collect()
+}
+
+/// Calls `f` with every way of picking five cards out of `cards`.
+fn for_each_five_card_hand(cards: &[Card], mut f: impl FnMut(Hand)) {
+    let n = cards.len();
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        f(Hand::from([
+                            cards[a], cards[b], cards[c], cards[d], cards[e],
+                        ]));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The strength of the best hand in `cards`, without checking them.
+pub(crate) fn best_strength(cards: &[Card]) -> HandStrength {
+    let mut best = HandStrength(0);
+    for_each_five_card_hand(cards, |h| best = best.max(HandStrength::from(h)));
+    best
+}
+
+/// Scores every five card `Hand` that can be made out of `cards`, and returns
+/// the best one.
+///
+/// ```
+/// # use poker::{*, HandScore::*, Rank::*};
+/// let cards = parse_cards("KS KD 2C 7H KH 7C 9S").unwrap();
+/// assert_eq!(
+///     best_hand(&cards),
+///     Ok((FullHouse{top_rank: King, bottom_rank: Seven}, Hand::of("7C 7H KD KH KS"))),
+/// );
+/// assert!(best_hand(&cards[..4]).is_err());
+/// assert!(best_hand(&parse_cards("KS KS 2C 7H KH 7C").unwrap()).is_err());
+/// ```
+pub fn best_hand(cards: &[Card]) -> Result<(HandScore, Hand), Undecodable> {
+    if cards.len() < 5 {
+        return Err(Undecodable(format!(
+            "{:?}: expected at least 5 cards",
+            cards
+        )));
+    }
+
+    if cards.iter().collect::<HashSet<_>>().len() != cards.len() {
+        return Err(Undecodable(format!(
+            "{:?}: expected all cards to be different",
+            cards
+        )));
+    }
+
+    let mut best = None;
+    for_each_five_card_hand(cards, |h| {
+        best = best.max(Some((HandStrength::from(h), h)));
+    });
+    let (_, best) = best.expect("there's at least one hand in 5 or more cards");
+
+    Ok((HandScore::from(best), best))
+}
+
+/// The best hand a hold'em player can make with their two hole cards and the
+/// board. The board can be the flop, the turn or the river, so 3 to 5 cards.
+///
+/// ```
+/// # use poker::{*, HandScore::*, Rank::*};
+/// let hole = parse_cards("AH 3H").unwrap();
+/// let board = parse_cards("2H 9H 4S 5C KH").unwrap();
+/// assert_eq!(
+///     best_holdem_hand(&hole, &board),
+///     Ok((Flush{ranks: [Ace, King, Nine, Three, Two]}, Hand::of("2H 3H 9H KH AH"))),
+/// );
+/// assert_eq!(
+///     best_holdem_hand(&hole, &board[..3]),
+///     Ok((HighCard{ranks: [Ace, Nine, Four, Three, Two]}, Hand::of("2H 3H 4S 9H AH"))),
+/// );
+/// assert!(best_holdem_hand(&hole[..1], &board).is_err());
+/// assert!(best_holdem_hand(&hole, &board[..2]).is_err());
+/// ```
+pub fn best_holdem_hand(hole: &[Card], board: &[Card]) -> Result<(HandScore, Hand), Undecodable> {
+    if hole.len() != 2 {
+        return Err(Undecodable(format!(
+            "{:?}: expected to be 2 hole cards",
+            hole
+        )));
+    }
+
+    if !(3..=5).contains(&board.len()) {
+        return Err(Undecodable(format!(
+            "{:?}: expected the board to be 3 to 5 cards",
+            board
+        )));
+    }
+
+    best_hand(&[hole, board].concat())
+}
+
+/// Like `winning_hands`, but each hand is a player's two hole cards, and they
+/// all share the `board`. Returns the winning hole cards.
+///
+/// ```
+/// # use poker::*;
+/// let board = "2H 9H 4S 5C KH";
+/// assert_eq!(winning_holdem_hands(&["AH 3H", "KS KD", "3S 6D"], board), vec!["AH 3H"]);
+/// // Both play the board.
+/// assert_eq!(winning_holdem_hands(&["2C 3C", "2D 3D"], "10S JS QS KS AS"), vec!["2C 3C", "2D 3D"]);
+/// ```
+pub fn winning_holdem_hands<'a>(holes: &[&'a str], board: &str) -> Vec<&'a str> {
+    let board = parse_cards(board).unwrap_or_else(|v| panic!("{:?}", v));
+
+    highest_scoring(
+        holes
+            .iter()
+            .map(|s| {
+                let hole = parse_cards(s).unwrap_or_else(|v| panic!("{:?}", v));
+                let (score, _) =
+                    best_holdem_hand(&hole, &board).unwrap_or_else(|v| panic!("{:?}", v));
+                (score, *s)
+            })
+            .collect(),
+    )
+}
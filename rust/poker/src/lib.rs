@@ -6,10 +6,34 @@ use std::{
     fmt,
 };
 
+mod deck;
+mod describe;
+mod equity;
+mod errors;
+mod holdem;
+mod notation;
+mod rng;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod strength;
+mod table;
+mod variants;
+
+pub use deck::*;
+pub use describe::*;
+pub use equity::*;
+pub use errors::*;
+pub use holdem::*;
+pub use notation::*;
+pub use strength::*;
+pub use table::*;
+pub use variants::*;
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Undecodable(pub String);
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Rank {
     AceLow,
@@ -35,6 +59,7 @@ impl TryFrom<&str> for Rank {
     /// # use poker::*;
     /// assert_eq!(Rank::try_from("2"), Ok(Rank::Two));
     /// assert_eq!(Rank::try_from("10"), Ok(Rank::Ten));
+    /// assert_eq!(Rank::try_from("T"), Ok(Rank::Ten));
     /// assert_eq!(Rank::try_from("K"), Ok(Rank::King));
     /// assert_eq!(Rank::try_from("?"), Err(Undecodable("\"?\": not a Rank".to_string())));
     /// ```
@@ -48,7 +73,7 @@ impl TryFrom<&str> for Rank {
             "7" => Ok(Rank::Seven),
             "8" => Ok(Rank::Eight),
             "9" => Ok(Rank::Nine),
-            "10" => Ok(Rank::Ten),
+            "10" | "T" => Ok(Rank::Ten),
             "J" => Ok(Rank::Jack),
             "Q" => Ok(Rank::Queen),
             "K" => Ok(Rank::King),
@@ -88,12 +113,13 @@ impl fmt::Display for Rank {
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Suit {
-    Clubs = 'C' as u8,
-    Diamonds = 'D' as u8,
-    Hearts = 'H' as u8,
-    Spades = 'S' as u8,
+    Clubs = b'C',
+    Diamonds = b'D',
+    Hearts = b'H',
+    Spades = b'S',
 }
 
 impl TryFrom<&char> for Suit {
@@ -102,14 +128,16 @@ impl TryFrom<&char> for Suit {
     /// ```
     /// # use poker::*;
     /// assert_eq!(Suit::try_from(&'D'), Ok(Suit::Diamonds));
+    /// assert_eq!(Suit::try_from(&'d'), Ok(Suit::Diamonds));
+    /// assert_eq!(Suit::try_from(&'♦'), Ok(Suit::Diamonds));
     /// assert_eq!(Suit::try_from(&'?'), Err(Undecodable("'?': not a Suit".to_string())));
     /// ```
     fn try_from(s: &char) -> Result<Self, Self::Error> {
         match s {
-            'D' => Ok(Suit::Diamonds),
-            'H' => Ok(Suit::Hearts),
-            'C' => Ok(Suit::Clubs),
-            'S' => Ok(Suit::Spades),
+            'D' | 'd' | '♦' => Ok(Suit::Diamonds),
+            'H' | 'h' | '♥' => Ok(Suit::Hearts),
+            'C' | 'c' | '♣' => Ok(Suit::Clubs),
+            'S' | 's' | '♠' => Ok(Suit::Spades),
             _ => Err(Undecodable(format!("{:?}: not a Suit", s))),
         }
     }
@@ -132,7 +160,7 @@ impl fmt::Display for Suit {
     /// assert_eq!(Suit::try_from(&'S').unwrap().to_string(), "S");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", char::from(self).to_string())
+        write!(f, "{}", char::from(self))
     }
 }
 
@@ -157,6 +185,8 @@ impl TryFrom<&str> for Card {
     /// ```
     /// # use poker::{*, Undecodable, Card, Rank::*, Suit::*};
     /// assert_eq!(Card::try_from("AS"), Ok(Card{rank: Ace, suit: Spades}));
+    /// assert_eq!(Card::try_from("Ts"), Ok(Card{rank: Ten, suit: Spades}));
+    /// assert_eq!(Card::try_from("10♠"), Ok(Card{rank: Ten, suit: Spades}));
     /// assert_eq!(Card::try_from("A"), Err(Undecodable("\"A\": expected to be at least two chars".to_string())));
     /// ```
     fn try_from(s: &str) -> Result<Self, Self::Error> {
@@ -198,6 +228,44 @@ impl Hand {
     }
 }
 
+impl From<[Card; 5]> for Hand {
+    /// ```
+    /// # use poker::{Card, Hand};
+    /// let cards = ["AS", "2S", "3S", "4S", "5S"].map(|c| Card::try_from(c).unwrap());
+    /// assert_eq!(Hand::from(cards), Hand::of("2S 3S 4S 5S AS"));
+    /// ```
+    fn from(mut cards: [Card; 5]) -> Self {
+        cards.sort();
+        Hand { cards }
+    }
+}
+
+impl TryFrom<&[Card]> for Hand {
+    type Error = Undecodable;
+
+    /// ```
+    /// # use poker::*;
+    /// let cards = parse_cards("AS 2S 3S 4S 5S").unwrap();
+    /// assert_eq!(Hand::try_from(&cards[..]), Ok(Hand::of("2S 3S 4S 5S AS")));
+    /// assert!(Hand::try_from(&cards[..4]).is_err());
+    /// assert!(Hand::try_from(&parse_cards("AS AS 3S 4S 5S").unwrap()[..]).is_err());
+    /// ```
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        let cards: [Card; 5] = cards
+            .try_into()
+            .map_err(|_| Undecodable(format!("{:?}: expected to be 5 cards", cards)))?;
+
+        if cards.iter().collect::<HashSet<_>>().len() != cards.len() {
+            return Err(Undecodable(format!(
+                "{:?}: expected all cards to be different",
+                cards
+            )));
+        }
+
+        Ok(Hand::from(cards))
+    }
+}
+
 impl TryFrom<&str> for Hand {
     type Error = Undecodable;
 
@@ -212,6 +280,7 @@ impl TryFrom<&str> for Hand {
     /// ]}));
     /// assert_eq!(Hand::try_from("AS"), Err(Undecodable("\"AS\": expected to be 5 cards".to_string())));
     /// assert_eq!(Hand::try_from("AS AS 3S 4S 5S"), Err(Undecodable("\"AS AS 3S 4S 5S\": expected all cards to be different".to_string())));
+    /// assert_eq!(Hand::try_from("AS 2S AS 3S 4S"), Err(Undecodable("\"AS 2S AS 3S 4S\": expected all cards to be different".to_string())));
     /// ```
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         let mut cards_v: Vec<Card> = s
@@ -223,10 +292,7 @@ impl TryFrom<&str> for Hand {
             return Err(Undecodable(format!("{:?}: expected to be 5 cards", s)));
         };
 
-        if !cards_v
-            .windows(2)
-            .all(|cs| if let [a, b] = cs { a != b } else { false })
-        {
+        if cards_v.iter().collect::<HashSet<_>>().len() != cards_v.len() {
             return Err(Undecodable(format!(
                 "{:?}: expected all cards to be different",
                 s
@@ -348,6 +414,7 @@ pub fn tally_ranks(cards: &[Card]) -> Vec<(usize, Rank)> {
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandScore {
     HighCard {
         ranks: [Rank; 5],
@@ -384,6 +451,23 @@ pub enum HandScore {
     },
 }
 
+impl HandScore {
+    /// Which variant this is, counting up from `HighCard`.
+    pub(crate) fn variant(&self) -> u8 {
+        match self {
+            HandScore::HighCard { .. } => 0,
+            HandScore::OnePair { .. } => 1,
+            HandScore::TwoPair { .. } => 2,
+            HandScore::ThreeOfAKind { .. } => 3,
+            HandScore::Straight { .. } => 4,
+            HandScore::Flush { .. } => 5,
+            HandScore::FullHouse { .. } => 6,
+            HandScore::FourOfAKind { .. } => 7,
+            HandScore::StraightFlush { .. } => 8,
+        }
+    }
+}
+
 impl From<Hand> for HandScore {
     /// ```
     /// # use poker::{Hand, HandScore, HandScore::*, Rank::*};
@@ -393,15 +477,18 @@ impl From<Hand> for HandScore {
     ///
     /// assert_eq!(score("AS 2S 3S 4S 5S"), StraightFlush{top_rank: Five});
     ///
-    /// assert_eq!(score("AS 3S 5S 7S 9S"), Flush{ranks: [Three, Five, Seven, Nine, Ace]});
+    /// assert_eq!(score("AS 3S 5S 7S 9S"), Flush{ranks: [Ace, Nine, Seven, Five, Three]});
+    /// assert_eq!(score("2C 4D 6H 8S KC"), HighCard{ranks: [King, Eight, Six, Four, Two]});
+    /// assert!(score("2C 4D 6H 8S KC") > score("3C 4D 6H 8S QC"));
     ///
     /// assert_eq!(score("AS AC AD AH 9H"), FourOfAKind{top_rank: Ace, bottom_rank: Nine});
     /// assert_eq!(score("AH 9C 9D 9H 9S"), FourOfAKind{top_rank: Nine, bottom_rank: Ace});
     /// ```
     fn from(h: Hand) -> Self {
+        // Highest first, so that comparing two of these compares the top cards first.
         let ranks: [Rank; 5] = {
             let mut r = [Rank::AceLow; 5];
-            for (i, c) in h.cards.iter().enumerate() {
+            for (i, c) in h.cards.iter().rev().enumerate() {
                 r[i] = c.rank;
             }
             r
@@ -451,16 +538,18 @@ impl From<&str> for HandScore {
     }
 }
 
-pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
-    if hands.len() == 0 {
+/// Keeps the entries tied for the highest score.
+pub(crate) fn highest_scoring<S: Ord + Clone, T: Ord>(mut hs: Vec<(S, T)>) -> Vec<T> {
+    if hs.is_empty() {
         return vec![];
     }
 
-    let mut hs: Vec<(HandScore, &str)> = hands.iter().map(|s| {
-        (HandScore::from(Hand::of(*s)), *s)
-    }).collect();
     hs.sort();
     let highest_score = hs.last().unwrap().0.clone();
-    hs.retain(|(hs, _s)| *hs == highest_score);
-    hs.iter().map(|(_hs, s)| *s).collect()
+    hs.retain(|(hs, _t)| *hs == highest_score);
+    hs.into_iter().map(|(_hs, t)| t).collect()
+}
+
+pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
+    winning_hands_by(&HighHand, hands)
 }
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::{Card, Hand, Rank, Suit, Undecodable};
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum TenStyle {
+    /// `10`
+    #[default]
+    Digits,
+    /// `T`
+    Letter,
+}
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum SuitStyle {
+    /// `S`
+    #[default]
+    Uppercase,
+    /// `s`
+    Lowercase,
+    /// `♠`
+    Symbol,
+}
+
+/// How cards are written down. `Rank::try_from`, `Suit::try_from` and
+/// `Card::try_from` read every notation; the methods here only read their
+/// own.
+///
+/// ```
+/// # use poker::*;
+/// let card = Card::try_from("10S").unwrap();
+/// assert_eq!(Notation::STANDARD.show(card).to_string(), "10S");
+/// assert_eq!(Notation::COMPACT.show(card).to_string(), "Ts");
+/// assert_eq!(Notation::SYMBOLS.show(card).to_string(), "10♠");
+/// assert_eq!(Notation::COMPACT.show(Hand::of("AH 10S 2C 3D 4S")).to_string(), "2c 3d 4s Ts Ah");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Notation {
+    pub ten: TenStyle,
+    pub suit: SuitStyle,
+}
+
+impl Notation {
+    /// The notation `Display` uses, like `10S`.
+    pub const STANDARD: Notation = Notation {
+        ten: TenStyle::Digits,
+        suit: SuitStyle::Uppercase,
+    };
+    /// Like `Ts`.
+    pub const COMPACT: Notation = Notation {
+        ten: TenStyle::Letter,
+        suit: SuitStyle::Lowercase,
+    };
+    /// Like `10♠`.
+    pub const SYMBOLS: Notation = Notation {
+        ten: TenStyle::Digits,
+        suit: SuitStyle::Symbol,
+    };
+
+    /// Wraps `value` so that it's displayed in this notation.
+    pub fn show<T>(self, value: T) -> InNotation<T> {
+        InNotation {
+            value,
+            notation: self,
+        }
+    }
+
+    fn only_this<T: Copy>(self, s: &str, value: T) -> Result<T, Undecodable>
+    where
+        InNotation<T>: fmt::Display,
+    {
+        if self.show(value).to_string() == s {
+            Ok(value)
+        } else {
+            Err(Undecodable(format!("{:?}: not in {:?}", s, self)))
+        }
+    }
+
+    /// ```
+    /// # use poker::*;
+    /// assert_eq!(Notation::COMPACT.parse_rank("T"), Ok(Rank::Ten));
+    /// assert!(Notation::COMPACT.parse_rank("10").is_err());
+    /// ```
+    pub fn parse_rank(self, s: &str) -> Result<Rank, Undecodable> {
+        self.only_this(s, Rank::try_from(s)?)
+    }
+
+    /// ```
+    /// # use poker::*;
+    /// assert_eq!(Notation::SYMBOLS.parse_suit(&'♥'), Ok(Suit::Hearts));
+    /// assert!(Notation::SYMBOLS.parse_suit(&'H').is_err());
+    /// ```
+    pub fn parse_suit(self, c: &char) -> Result<Suit, Undecodable> {
+        self.only_this(&c.to_string(), Suit::try_from(c)?)
+    }
+
+    /// ```
+    /// # use poker::{*, Rank::*, Suit::*};
+    /// assert_eq!(Notation::COMPACT.parse_card("Qd"), Ok(Card{rank: Queen, suit: Diamonds}));
+    /// assert!(Notation::COMPACT.parse_card("QD").is_err());
+    /// ```
+    pub fn parse_card(self, s: &str) -> Result<Card, Undecodable> {
+        self.only_this(s, Card::try_from(s)?)
+    }
+}
+
+/// Something displayed in a `Notation`, made by `Notation::show`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InNotation<T> {
+    pub value: T,
+    pub notation: Notation,
+}
+
+impl fmt::Display for InNotation<Rank> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.notation.ten, self.value) {
+            (TenStyle::Letter, Rank::Ten) => write!(f, "T"),
+            (_, r) => write!(f, "{}", r),
+        }
+    }
+}
+
+impl fmt::Display for InNotation<Suit> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = char::from(&self.value);
+        match self.notation.suit {
+            SuitStyle::Uppercase => write!(f, "{}", c),
+            SuitStyle::Lowercase => write!(f, "{}", c.to_ascii_lowercase()),
+            SuitStyle::Symbol => write!(
+                f,
+                "{}",
+                match self.value {
+                    Suit::Clubs => '♣',
+                    Suit::Diamonds => '♦',
+                    Suit::Hearts => '♥',
+                    Suit::Spades => '♠',
+                }
+            ),
+        }
+    }
+}
+
+impl fmt::Display for InNotation<Card> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.notation.show(self.value.rank),
+            self.notation.show(self.value.suit)
+        )
+    }
+}
+
+impl fmt::Display for InNotation<Hand> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.value
+                .cards
+                .iter()
+                .map(|c| self.notation.show(*c).to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
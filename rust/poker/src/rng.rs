@@ -0,0 +1,32 @@
+/// A small seeded pseudo-random number generator (SplitMix64), so results can
+/// be reproduced from a seed without pulling in a crate.
+#[derive(Clone, Debug)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. The modulo bias is negligible for deck sized `n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Shuffles the first `n` elements of `v` into a uniformly random
+    /// selection of its elements, leaving the rest in some other order.
+    pub(crate) fn partial_shuffle<T>(&mut self, v: &mut [T], n: usize) {
+        for i in 0..n.min(v.len()) {
+            let j = i + self.below(v.len() - i);
+            v.swap(i, j);
+        }
+    }
+}
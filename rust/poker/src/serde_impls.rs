@@ -0,0 +1,47 @@
+//! `Card`s and `Hand`s are (de)serialized as they're written, like `"10S"` and
+//! `"2S 3S 4S 5S 6S"`, reading any `Notation`. `Rank`, `Suit` and `HandScore`
+//! derive theirs.
+//!
+//! ```
+//! # use poker::*;
+//! let hand = Hand::of("AS 10S JS QS KS");
+//! let json = serde_json::to_string(&hand).unwrap();
+//! assert_eq!(json, r#""10S JS QS KS AS""#);
+//! assert_eq!(serde_json::from_str::<Hand>(&json).unwrap(), hand);
+//! assert_eq!(serde_json::from_str::<Card>(r#""T♠""#).unwrap(), Card::try_from("10S").unwrap());
+//! assert!(serde_json::from_str::<Hand>(r#""AS AS JS QS KS""#).is_err());
+//!
+//! let json = serde_json::to_string(&HandScore::from(hand)).unwrap();
+//! assert_eq!(json, r#"{"StraightFlush":{"top_rank":"Ace"}}"#);
+//! assert_eq!(serde_json::from_str::<HandScore>(&json).unwrap(), HandScore::from(hand));
+//! ```
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Card, Hand};
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Card::try_from(s.as_str()).map_err(|e| de::Error::custom(e.0))
+    }
+}
+
+impl Serialize for Hand {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Hand {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Hand::try_from(s.as_str()).map_err(|e| de::Error::custom(e.0))
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::OnceLock;
+
+use crate::{Card, Hand, HandScore, Rank};
+
+/// A `HandScore` packed into an integer, so that comparing two strengths
+/// compares the hands exactly like comparing their `HandScore`s would.
+///
+/// The variant goes in bits 20 and up, followed by the variant's ranks, four
+/// bits each, in the order they're compared.
+///
+/// ```
+/// # use poker::*;
+/// fn strength(s: &str) -> HandStrength {
+///     HandStrength::from(Hand::of(s))
+/// }
+///
+/// assert!(strength("2C 4D 6H 8S KC") > strength("3C 4D 6H 8S QC"));
+/// assert!(strength("AS 2S 3S 4S 5S") > strength("AS AC AD AH KH"));
+/// assert_eq!(strength("2C 3C 4C 5C 6C"), strength("2D 3D 4D 5D 6D"));
+/// assert_eq!(
+///     strength("KC KH QC QH JC"),
+///     HandStrength::from(&HandScore::from("KC KH QC QH JC")),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandStrength(pub u32);
+
+const HIGH_CARD: u32 = 0;
+const ONE_PAIR: u32 = 1;
+const TWO_PAIR: u32 = 2;
+const THREE_OF_A_KIND: u32 = 3;
+const STRAIGHT: u32 = 4;
+const FLUSH: u32 = 5;
+const FULL_HOUSE: u32 = 6;
+const FOUR_OF_A_KIND: u32 = 7;
+const STRAIGHT_FLUSH: u32 = 8;
+
+fn pack(variant: u32, ranks: &[Rank]) -> HandStrength {
+    let mut s = variant;
+    for i in 0..5 {
+        s = (s << 4) | ranks.get(i).map_or(0, |r| *r as u32);
+    }
+    HandStrength(s)
+}
+
+/// Every `Rank`, indexed by its value.
+const RANKS: [Rank; 14] = {
+    use Rank::*;
+
+    [
+        AceLow, Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Jack, Queen, King, Ace,
+    ]
+};
+
+/// One bit per `Rank`, `AceLow` included.
+const RANK_MASKS: usize = 1 << RANKS.len();
+
+/// Strengths of five different ranks, indexed by their rank mask: the first
+/// table for when they're not all the same suit, the second for a flush.
+fn distinct_ranks_tables() -> &'static (Vec<HandStrength>, Vec<HandStrength>) {
+    static TABLES: OnceLock<(Vec<HandStrength>, Vec<HandStrength>)> = OnceLock::new();
+
+    TABLES.get_or_init(|| {
+        let mut unsuited = vec![HandStrength(0); RANK_MASKS];
+        let mut suited = vec![HandStrength(0); RANK_MASKS];
+
+        for mask in (0..RANK_MASKS).filter(|m| m.count_ones() == 5) {
+            let top = mask.ilog2() as usize;
+            let straight_top = if mask == 0b11111 << (top - 4) {
+                Some(RANKS[top])
+            } else if mask == (1 << Rank::Ace as usize) | (0b1111 << Rank::Two as usize) {
+                Some(Rank::Five)
+            } else {
+                None
+            };
+
+            (unsuited[mask], suited[mask]) = match straight_top {
+                Some(r) => (pack(STRAIGHT, &[r]), pack(STRAIGHT_FLUSH, &[r])),
+                None => {
+                    let ranks: Vec<Rank> = (0..RANKS.len())
+                        .rev()
+                        .filter(|n| mask & (1 << n) != 0)
+                        .map(|n| RANKS[n])
+                        .collect();
+                    (pack(HIGH_CARD, &ranks), pack(FLUSH, &ranks))
+                }
+            };
+        }
+
+        (unsuited, suited)
+    })
+}
+
+impl From<&[Card; 5]> for HandStrength {
+    fn from(cards: &[Card; 5]) -> Self {
+        let mut counts = [0u8; RANKS.len()];
+        let mut mask = 0usize;
+        for c in cards {
+            counts[c.rank as usize] += 1;
+            mask |= 1 << c.rank as usize;
+        }
+
+        if mask.count_ones() == 5 {
+            let (unsuited, suited) = distinct_ranks_tables();
+            let suit = cards[0].suit;
+            return if cards.iter().all(|c| c.suit == suit) {
+                suited[mask]
+            } else {
+                unsuited[mask]
+            };
+        }
+
+        // The ranks that make up a pair or more, then the kickers, highest
+        // first: that's already the order their variant compares them in.
+        let mut ranks = [Rank::AceLow; 5];
+        let mut len = 0;
+        let mut most = 0;
+        for count in (1..=4).rev() {
+            for (n, c) in counts.iter().enumerate().rev() {
+                if *c == count {
+                    ranks[len] = RANKS[n];
+                    len += 1;
+                    most = most.max(count);
+                }
+            }
+        }
+
+        let variant = match (len, most) {
+            (2, 4) => FOUR_OF_A_KIND,
+            (2, _) => FULL_HOUSE,
+            (3, 3) => THREE_OF_A_KIND,
+            (3, _) => TWO_PAIR,
+            _ => ONE_PAIR,
+        };
+        pack(variant, &ranks[..len])
+    }
+}
+
+impl From<Hand> for HandStrength {
+    fn from(h: Hand) -> Self {
+        HandStrength::from(&h.cards)
+    }
+}
+
+impl From<&HandScore> for HandStrength {
+    fn from(hs: &HandScore) -> Self {
+        match hs {
+            HandScore::HighCard { ranks } => pack(HIGH_CARD, ranks),
+            HandScore::OnePair {
+                top_rank,
+                other_ranks,
+            } => pack(ONE_PAIR, &[&[*top_rank], &other_ranks[..]].concat()),
+            HandScore::TwoPair {
+                top_rank,
+                second_rank,
+                other_rank,
+            } => pack(TWO_PAIR, &[*top_rank, *second_rank, *other_rank]),
+            HandScore::ThreeOfAKind {
+                top_rank,
+                other_ranks,
+            } => pack(THREE_OF_A_KIND, &[&[*top_rank], &other_ranks[..]].concat()),
+            HandScore::Straight { top_rank } => pack(STRAIGHT, &[*top_rank]),
+            HandScore::Flush { ranks } => pack(FLUSH, ranks),
+            HandScore::FullHouse {
+                top_rank,
+                bottom_rank,
+            } => pack(FULL_HOUSE, &[*top_rank, *bottom_rank]),
+            HandScore::FourOfAKind {
+                top_rank,
+                bottom_rank,
+            } => pack(FOUR_OF_A_KIND, &[*top_rank, *bottom_rank]),
+            HandScore::StraightFlush { top_rank } => pack(STRAIGHT_FLUSH, &[*top_rank]),
+        }
+    }
+}
@@ -0,0 +1,690 @@
+use std::{collections::HashSet, error::Error, fmt};
+
+use crate::{best_holdem_hand, parse_cards, Card, HandScore, Undecodable};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    /// Opens the betting on a street with this many chips.
+    Bet(u64),
+    /// Raises the bet to this many chips in total for the street.
+    RaiseTo(u64),
+    /// Puts in every chip left, as a call, a bet or a raise.
+    AllIn,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TableError {
+    NotEnoughPlayers,
+    DuplicatePlayer(String),
+    UnknownPlayer(String),
+    /// Blinds must be more than nothing, and the small one no bigger than the
+    /// big one; stacks must be more than nothing.
+    BadAmounts,
+    OutOfTurn {
+        player: String,
+        expected: Option<String>,
+    },
+    IllegalAction {
+        player: String,
+        action: Action,
+        reason: &'static str,
+    },
+    /// Dealing the next street before the betting on this one is over.
+    BettingNotOver,
+    /// Acting or dealing once the hand has been decided.
+    HandOver,
+    /// Settling before the hand has been decided.
+    HandNotOver,
+    WrongBoard {
+        street: Street,
+        cards: usize,
+    },
+    BadCards(Undecodable),
+    MissingHoleCards(String),
+    /// A line of a hand history that doesn't parse.
+    Unreadable(String),
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::NotEnoughPlayers => write!(f, "expected at least 2 players"),
+            TableError::DuplicatePlayer(p) => write!(f, "{:?}: already seated", p),
+            TableError::UnknownPlayer(p) => write!(f, "{:?}: not seated", p),
+            TableError::BadAmounts => write!(f, "expected positive stacks and blinds"),
+            TableError::OutOfTurn {
+                player,
+                expected: Some(e),
+            } => write!(f, "{:?}: not their turn, it's {:?}'s", player, e),
+            TableError::OutOfTurn {
+                player,
+                expected: None,
+            } => write!(f, "{:?}: not their turn, the betting is over", player),
+            TableError::IllegalAction {
+                player,
+                action,
+                reason,
+            } => write!(f, "{:?}: can't {:?}, {}", player, action, reason),
+            TableError::BettingNotOver => write!(f, "the betting isn't over"),
+            TableError::HandOver => write!(f, "the hand is over"),
+            TableError::HandNotOver => write!(f, "the hand isn't over"),
+            TableError::WrongBoard { street, cards } => {
+                write!(f, "{} cards: not what comes after the {:?}", cards, street)
+            }
+            TableError::BadCards(Undecodable(e)) => write!(f, "{}", e),
+            TableError::MissingHoleCards(p) => write!(f, "{:?}: no hole cards to show", p),
+            TableError::Unreadable(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for TableError {}
+
+impl From<Undecodable> for TableError {
+    fn from(e: Undecodable) -> Self {
+        TableError::BadCards(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Player {
+    name: String,
+    stack: u64,
+    /// Chips put in the pot this hand, and on this street.
+    put_in: u64,
+    on_street: u64,
+    hole: Vec<Card>,
+    folded: bool,
+    /// Whether they've acted since the last full raise.
+    acted: bool,
+}
+
+/// One hand of no-limit hold'em, from the blinds to the showdown.
+///
+/// The first seat posts the small blind and the second the big blind, so in
+/// a heads-up hand the first seat is also the button.
+///
+/// ```
+/// # use poker::*;
+/// let mut hand = HoldemHand::new(&[("Ann", 100), ("Bob", 100), ("Cy", 100)], 1, 2).unwrap();
+/// assert_eq!(hand.to_act(), Some("Cy"));
+/// hand.act("Cy", Action::RaiseTo(6)).unwrap();
+/// hand.act("Ann", Action::Fold).unwrap();
+/// assert!(hand.act("Bob", Action::RaiseTo(8)).is_err());
+/// hand.act("Bob", Action::Call).unwrap();
+/// assert_eq!(hand.to_act(), None);
+/// assert_eq!(hand.pot(), 13);
+///
+/// hand.deal_board(&parse_cards("2C 7D KH").unwrap()).unwrap();
+/// assert_eq!(hand.street(), Street::Flop);
+/// hand.act("Bob", Action::Bet(10)).unwrap();
+/// hand.act("Cy", Action::Fold).unwrap();
+///
+/// let settlement = hand.settle().unwrap();
+/// assert_eq!(settlement.net("Bob"), Some(7));
+/// assert_eq!(settlement.net("Cy"), Some(-6));
+/// assert_eq!(settlement.net("Ann"), Some(-1));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HoldemHand {
+    players: Vec<Player>,
+    board: Vec<Card>,
+    street: Street,
+    to_act: Option<usize>,
+    current_bet: u64,
+    min_raise: u64,
+    big_blind: u64,
+}
+
+impl HoldemHand {
+    pub fn new(
+        seats: &[(&str, u64)],
+        small_blind: u64,
+        big_blind: u64,
+    ) -> Result<Self, TableError> {
+        if seats.len() < 2 {
+            return Err(TableError::NotEnoughPlayers);
+        }
+
+        let mut names = HashSet::new();
+        if let Some((name, _)) = seats.iter().find(|(name, _)| !names.insert(*name)) {
+            return Err(TableError::DuplicatePlayer(name.to_string()));
+        }
+
+        if small_blind == 0 || small_blind > big_blind || seats.iter().any(|(_, s)| *s == 0) {
+            return Err(TableError::BadAmounts);
+        }
+
+        let mut hand = HoldemHand {
+            players: seats
+                .iter()
+                .map(|(name, stack)| Player {
+                    name: name.to_string(),
+                    stack: *stack,
+                    put_in: 0,
+                    on_street: 0,
+                    hole: vec![],
+                    folded: false,
+                    acted: false,
+                })
+                .collect(),
+            board: vec![],
+            street: Street::Preflop,
+            to_act: None,
+            current_bet: 0,
+            min_raise: big_blind,
+            big_blind,
+        };
+
+        hand.put_in(0, small_blind);
+        hand.put_in(1, big_blind);
+        hand.current_bet = big_blind;
+        hand.to_act = hand.next_to_act(1);
+
+        Ok(hand)
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// Every chip put in so far.
+    pub fn pot(&self) -> u64 {
+        self.players.iter().map(|p| p.put_in).sum()
+    }
+
+    /// Who has to act next, or `None` if the betting on this street is over.
+    pub fn to_act(&self) -> Option<&str> {
+        self.to_act.map(|i| self.players[i].name.as_str())
+    }
+
+    fn seat(&self, name: &str) -> Result<usize, TableError> {
+        self.players
+            .iter()
+            .position(|p| p.name == name)
+            .ok_or_else(|| TableError::UnknownPlayer(name.to_string()))
+    }
+
+    fn put_in(&mut self, i: usize, chips: u64) {
+        let p = &mut self.players[i];
+        let chips = chips.min(p.stack);
+        p.stack -= chips;
+        p.put_in += chips;
+        p.on_street += chips;
+    }
+
+    fn in_hand(&self) -> usize {
+        self.players.iter().filter(|p| !p.folded).count()
+    }
+
+    fn needs_to_act(&self, i: usize) -> bool {
+        let p = &self.players[i];
+        let can_act = |p: &Player| !p.folded && p.stack > 0;
+        let alone = self.players.iter().filter(|p| can_act(p)).count() <= 1;
+
+        // Anyone short of the bet has to act on it. Otherwise they only have
+        // to if they haven't yet, and someone else can still bet against them.
+        can_act(p) && (p.on_street < self.current_bet || (!p.acted && !alone))
+    }
+
+    fn next_to_act(&self, after: usize) -> Option<usize> {
+        if self.in_hand() <= 1 {
+            return None;
+        }
+
+        let n = self.players.len();
+        (1..=n)
+            .map(|k| (after + k) % n)
+            .find(|i| self.needs_to_act(*i))
+    }
+
+    fn is_over(&self) -> bool {
+        self.in_hand() <= 1 || (self.to_act.is_none() && self.street == Street::River)
+    }
+
+    pub fn act(&mut self, player: &str, action: Action) -> Result<(), TableError> {
+        if self.is_over() {
+            return Err(TableError::HandOver);
+        }
+
+        let i = self.seat(player)?;
+        if self.to_act != Some(i) {
+            return Err(TableError::OutOfTurn {
+                player: player.to_string(),
+                expected: self.to_act().map(str::to_string),
+            });
+        }
+
+        let illegal = |reason| TableError::IllegalAction {
+            player: player.to_string(),
+            action,
+            reason,
+        };
+        let p = &self.players[i];
+        let to_call = self.current_bet - p.on_street;
+        match action {
+            Action::Fold => self.players[i].folded = true,
+            Action::Check if to_call > 0 => return Err(illegal("there's a bet to call")),
+            Action::Check => {}
+            Action::Call if to_call == 0 => return Err(illegal("there's nothing to call")),
+            Action::Call => self.put_in(i, to_call),
+            Action::Bet(_) if self.current_bet > 0 => {
+                return Err(illegal("there's already a bet to raise"))
+            }
+            Action::RaiseTo(_) if self.current_bet == 0 => {
+                return Err(illegal("there's no bet to raise"))
+            }
+            Action::Bet(to) | Action::RaiseTo(to) => self.raise_to(i, to).map_err(illegal)?,
+            Action::AllIn if p.on_street + p.stack <= self.current_bet => self.put_in(i, p.stack),
+            Action::AllIn => self.raise_to(i, p.on_street + p.stack).map_err(illegal)?,
+        }
+
+        self.players[i].acted = true;
+        self.to_act = self.next_to_act(i);
+        Ok(())
+    }
+
+    fn raise_to(&mut self, i: usize, to: u64) -> Result<(), &'static str> {
+        let p = &self.players[i];
+        if p.acted {
+            return Err("the last raise wasn't a full one");
+        }
+        if to <= self.current_bet {
+            return Err("it has to be more than the current bet");
+        }
+        if to - p.on_street > p.stack {
+            return Err("there aren't enough chips");
+        }
+
+        let all_in = to - p.on_street == p.stack;
+        let raise = to - self.current_bet;
+        if raise < self.min_raise && !all_in {
+            return Err("it's less than the minimum raise");
+        }
+
+        // Only a full raise lets everyone who's acted raise again.
+        if raise >= self.min_raise {
+            self.min_raise = raise;
+            for (j, p) in self.players.iter_mut().enumerate() {
+                p.acted &= j == i;
+            }
+        }
+        self.current_bet = to;
+        self.put_in(i, to - self.players[i].on_street);
+        Ok(())
+    }
+
+    /// Deals a player's hole cards. They're only needed for a showdown.
+    pub fn deal_hole(&mut self, player: &str, cards: &[Card]) -> Result<(), TableError> {
+        let i = self.seat(player)?;
+        if cards.len() != 2 {
+            return Err(TableError::BadCards(Undecodable(format!(
+                "{:?}: expected to be 2 hole cards",
+                cards
+            ))));
+        }
+
+        self.check_unseen(cards)?;
+        self.players[i].hole = cards.to_vec();
+        Ok(())
+    }
+
+    /// Deals the flop, turn or river, once the betting before it is over.
+    pub fn deal_board(&mut self, cards: &[Card]) -> Result<(), TableError> {
+        if self.is_over() {
+            return Err(TableError::HandOver);
+        }
+        if self.to_act.is_some() {
+            return Err(TableError::BettingNotOver);
+        }
+
+        let (next, count) = match self.street {
+            Street::Preflop => (Street::Flop, 3),
+            Street::Flop => (Street::Turn, 1),
+            Street::Turn | Street::River => (Street::River, 1),
+        };
+        if cards.len() != count {
+            return Err(TableError::WrongBoard {
+                street: self.street,
+                cards: cards.len(),
+            });
+        }
+
+        self.check_unseen(cards)?;
+        self.board.extend(cards);
+        self.street = next;
+        for p in self.players.iter_mut() {
+            p.on_street = 0;
+            p.acted = false;
+        }
+        self.current_bet = 0;
+        self.min_raise = self.big_blind;
+
+        // After the flop the small blind acts first, but heads-up the big
+        // blind does, since the small blind has the button.
+        let n = self.players.len();
+        let first = if n == 2 { 1 } else { 0 };
+        self.to_act = self.next_to_act(first + n - 1);
+        Ok(())
+    }
+
+    fn check_unseen(&self, cards: &[Card]) -> Result<(), TableError> {
+        let mut seen: HashSet<&Card> = self
+            .players
+            .iter()
+            .flat_map(|p| &p.hole)
+            .chain(&self.board)
+            .collect();
+        match cards.iter().find(|c| !seen.insert(c)) {
+            Some(c) => Err(TableError::BadCards(Undecodable(format!(
+                "{}: already dealt",
+                c
+            )))),
+            None => Ok(()),
+        }
+    }
+
+    /// Splits the pot, and side pots, between the winners.
+    pub fn settle(&self) -> Result<Settlement, TableError> {
+        if !self.is_over() {
+            return Err(TableError::HandNotOver);
+        }
+
+        // Each distinct amount put in caps a pot: everybody contributes up to
+        // it, and whoever didn't fold and put in at least that much can win.
+        let mut levels: Vec<u64> = self.players.iter().map(|p| p.put_in).collect();
+        levels.sort();
+        levels.dedup();
+
+        let mut pots: Vec<Pot> = vec![];
+        let mut below = 0;
+        for level in levels.into_iter().filter(|l| *l > 0) {
+            let amount: u64 = self
+                .players
+                .iter()
+                .map(|p| p.put_in.min(level) - p.put_in.min(below))
+                .sum();
+            below = level;
+            let eligible: Vec<usize> = (0..self.players.len())
+                .filter(|i| !self.players[*i].folded && self.players[*i].put_in >= level)
+                .collect();
+
+            match pots.last_mut() {
+                Some(pot) if pot.eligible == eligible || eligible.is_empty() => {
+                    pot.amount += amount
+                }
+                _ => pots.push(Pot {
+                    amount,
+                    eligible,
+                    winners: vec![],
+                    score: None,
+                }),
+            }
+        }
+
+        let mut won = vec![0; self.players.len()];
+        for pot in pots.iter_mut() {
+            if let [only] = pot.eligible[..] {
+                pot.winners = vec![only];
+            } else {
+                let mut scores = vec![];
+                for i in pot.eligible.iter() {
+                    let p = &self.players[*i];
+                    if p.hole.len() != 2 {
+                        return Err(TableError::MissingHoleCards(p.name.clone()));
+                    }
+                    scores.push((best_holdem_hand(&p.hole, &self.board)?.0, *i));
+                }
+                let best = scores.iter().map(|(s, _)| s).max().cloned();
+                pot.winners = scores
+                    .iter()
+                    .filter(|(s, _)| Some(s) == best.as_ref())
+                    .map(|(_, i)| *i)
+                    .collect();
+                pot.score = best;
+            }
+
+            // Odd chips go to the winners closest to the small blind.
+            let share = pot.amount / pot.winners.len() as u64;
+            let odd = pot.amount as usize % pot.winners.len();
+            for (k, i) in pot.winners.iter().enumerate() {
+                won[*i] += share + u64::from(k < odd);
+            }
+        }
+
+        let name = |i: &usize| self.players[*i].name.clone();
+        Ok(Settlement {
+            pots: pots
+                .iter()
+                .map(|p| SettledPot {
+                    amount: p.amount,
+                    eligible: p.eligible.iter().map(name).collect(),
+                    winners: p.winners.iter().map(name).collect(),
+                    score: p.score.clone(),
+                })
+                .collect(),
+            players: self
+                .players
+                .iter()
+                .zip(won)
+                .map(|(p, won)| ChipMovement {
+                    player: p.name.clone(),
+                    put_in: p.put_in,
+                    won,
+                })
+                .collect(),
+        })
+    }
+}
+
+struct Pot {
+    amount: u64,
+    eligible: Vec<usize>,
+    winners: Vec<usize>,
+    score: Option<HandScore>,
+}
+
+/// The main pot, or a side pot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SettledPot {
+    pub amount: u64,
+    pub eligible: Vec<String>,
+    pub winners: Vec<String>,
+    /// The winning hand, if it came to a showdown.
+    pub score: Option<HandScore>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChipMovement {
+    pub player: String,
+    pub put_in: u64,
+    pub won: u64,
+}
+
+impl ChipMovement {
+    pub fn net(&self) -> i64 {
+        self.won as i64 - self.put_in as i64
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Settlement {
+    /// The main pot first, then the side pots.
+    pub pots: Vec<SettledPot>,
+    /// In seat order.
+    pub players: Vec<ChipMovement>,
+}
+
+impl Settlement {
+    pub fn net(&self, player: &str) -> Option<i64> {
+        self.players
+            .iter()
+            .find(|m| m.player == player)
+            .map(ChipMovement::net)
+    }
+}
+
+/// A `TableError` on a line of a hand history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayError {
+    /// Counting from 1.
+    pub line: usize,
+    pub error: TableError,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl Error for ReplayError {}
+
+fn parse_chips(s: &str) -> Result<u64, TableError> {
+    s.parse()
+        .map_err(|_| TableError::Unreadable(format!("{:?}: not a number of chips", s)))
+}
+
+/// A hand history as far as it's been replayed: the seats, until the blinds
+/// start the hand.
+#[derive(Default)]
+struct Replay<'a> {
+    seats: Vec<(&'a str, u64)>,
+    hand: Option<HoldemHand>,
+}
+
+impl<'a> Replay<'a> {
+    fn hand(&mut self) -> Result<&mut HoldemHand, TableError> {
+        self.hand
+            .as_mut()
+            .ok_or_else(|| TableError::Unreadable("no blinds yet".to_string()))
+    }
+
+    fn line(&mut self, words: &[&'a str]) -> Result<(), TableError> {
+        match *words {
+            ["seat", player, stack] if self.hand.is_none() => {
+                self.seats.push((player, parse_chips(stack)?));
+            }
+            ["blinds", small, big] if self.hand.is_none() => {
+                let (small, big) = (parse_chips(small)?, parse_chips(big)?);
+                self.hand = Some(HoldemHand::new(&self.seats, small, big)?);
+            }
+            ["deal", player, ref cards @ ..] => {
+                let cards = parse_cards(&cards.join(" "))?;
+                self.hand()?.deal_hole(player, &cards)?;
+            }
+            ["flop" | "turn" | "river", ref cards @ ..] => {
+                let cards = parse_cards(&cards.join(" "))?;
+                self.hand()?.deal_board(&cards)?;
+            }
+            [player, ref action @ ..] => {
+                let action = match *action {
+                    ["folds"] => Action::Fold,
+                    ["checks"] => Action::Check,
+                    ["calls"] => Action::Call,
+                    ["bets", chips] => Action::Bet(parse_chips(chips)?),
+                    ["raises", "to", chips] => Action::RaiseTo(parse_chips(chips)?),
+                    ["all-in"] => Action::AllIn,
+                    _ => {
+                        return Err(TableError::Unreadable(format!(
+                            "{:?}: not an event",
+                            words.join(" ")
+                        )))
+                    }
+                };
+                self.hand()?.act(player, action)?;
+            }
+            [] => {}
+        }
+        Ok(())
+    }
+}
+
+/// Plays a hand history through a `HoldemHand`, and settles it.
+///
+/// A history has one line per event; blank lines and lines starting with `#`
+/// are skipped:
+///
+/// - `seat <player> <stack>`, for every player, in seat order
+/// - `blinds <small> <big>`, after the seats
+/// - `deal <player> <card> <card>`, for the hole cards
+/// - `flop <card> <card> <card>`, `turn <card>` and `river <card>`
+/// - `<player> folds`, `checks`, `calls`, `bets <chips>`,
+///   `raises to <chips>` or `all-in`
+///
+/// ```
+/// # use poker::*;
+/// let history = "
+///     seat Ann 200
+///     seat Bob 50
+///     seat Cy 200
+///     blinds 5 10
+///     deal Ann AS AH
+///     deal Bob KS KH
+///     deal Cy QS QH
+///     Cy raises to 30
+///     Ann calls
+///     Bob all-in
+///     Cy calls
+///     Ann calls
+///     flop 2C 7D 9H
+///     Ann bets 50
+///     Cy calls
+///     turn 3S
+///     Ann checks
+///     Cy checks
+///     river KD
+///     Ann checks
+///     Cy checks
+/// ";
+/// let settlement = replay(history).unwrap();
+///
+/// // Bob's kings win the main pot, and Ann's aces the side pot.
+/// assert_eq!(settlement.pots[0].amount, 150);
+/// assert_eq!(settlement.pots[0].winners, vec!["Bob"]);
+/// assert_eq!(settlement.pots[1].amount, 100);
+/// assert_eq!(settlement.pots[1].winners, vec!["Ann"]);
+/// assert_eq!(settlement.net("Ann"), Some(0));
+/// assert_eq!(settlement.net("Bob"), Some(100));
+/// assert_eq!(settlement.net("Cy"), Some(-100));
+///
+/// let e = replay("seat Ann 200\nseat Bob 50\nblinds 5 10\nBob checks").unwrap_err();
+/// assert_eq!(e.line, 4);
+/// assert!(matches!(e.error, TableError::OutOfTurn { .. }));
+/// ```
+pub fn replay(history: &str) -> Result<Settlement, ReplayError> {
+    let mut replay = Replay::default();
+    let mut last = 0;
+
+    for (n, line) in history.lines().enumerate() {
+        let words: Vec<&str> = line.split_ascii_whitespace().collect();
+        if words.first().is_none_or(|w| w.starts_with('#')) {
+            continue;
+        }
+
+        last = n + 1;
+        replay
+            .line(&words)
+            .map_err(|error| ReplayError { line: last, error })?;
+    }
+
+    replay
+        .hand()
+        .and_then(|hand| hand.settle())
+        .map_err(|error| ReplayError { line: last, error })
+}
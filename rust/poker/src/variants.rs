@@ -0,0 +1,289 @@
+use std::{cmp::Ordering, cmp::Reverse, collections::HashSet};
+
+use crate::{highest_scoring, tally_ranks, Card, Hand, HandScore, Rank, Undecodable};
+
+/// How a poker game ranks five card hands: the higher `Score` wins.
+pub trait RankingRules {
+    type Score: Ord + Clone;
+
+    fn score(&self, hand: &str) -> Result<Self::Score, Undecodable>;
+}
+
+/// Like `winning_hands`, but ranking the hands by `rules`.
+///
+/// ```
+/// # use poker::*;
+/// let hands = ["2S 3S 4D 5H 7C", "2C 3C 4C 5C 6C", "AS 2D 3H 4C 5S"];
+/// assert_eq!(winning_hands_by(&HighHand, &hands), vec!["2C 3C 4C 5C 6C"]);
+/// assert_eq!(winning_hands_by(&DeuceToSeven, &hands), vec!["2S 3S 4D 5H 7C"]);
+/// assert_eq!(winning_hands_by(&AceToFive, &hands), vec!["AS 2D 3H 4C 5S"]);
+/// ```
+pub fn winning_hands_by<'a, R: RankingRules>(rules: &R, hands: &[&'a str]) -> Vec<&'a str> {
+    highest_scoring(
+        hands
+            .iter()
+            .map(|s| (rules.score(s).unwrap_or_else(|v| panic!("{:?}", v)), *s))
+            .collect(),
+    )
+}
+
+/// Standard high hand poker, as `winning_hands` plays it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HighHand;
+
+impl RankingRules for HighHand {
+    type Score = HandScore;
+
+    fn score(&self, hand: &str) -> Result<Self::Score, Undecodable> {
+        Ok(HandScore::from(Hand::try_from(hand)?))
+    }
+}
+
+/// Short-deck (six plus) hold'em hands: there are no 2s to 5s, so the ace
+/// plays low in A-6-7-8-9, and a flush beats a full house.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(
+///     winning_hands_by(&ShortDeck, &["6H 7H 9H JH KH", "QS QD QC 6C 6S"]),
+///     vec!["6H 7H 9H JH KH"],
+/// );
+/// assert_eq!(
+///     winning_hands_by(&ShortDeck, &["AS 6D 7H 8C 9S", "KS KD KH 6C 7S"]),
+///     vec!["AS 6D 7H 8C 9S"],
+/// );
+/// assert!(ShortDeck.score("2S 6D 7H 8C 9S").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ShortDeck;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ShortDeckScore(pub HandScore);
+
+impl ShortDeckScore {
+    fn variant(&self) -> u8 {
+        match self.0.variant() {
+            5 => 6,
+            6 => 5,
+            v => v,
+        }
+    }
+}
+
+impl Ord for ShortDeckScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.variant(), &self.0).cmp(&(other.variant(), &other.0))
+    }
+}
+
+impl PartialOrd for ShortDeckScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RankingRules for ShortDeck {
+    type Score = ShortDeckScore;
+
+    fn score(&self, hand: &str) -> Result<Self::Score, Undecodable> {
+        use Rank::*;
+
+        let hand = Hand::try_from(hand)?;
+        if let Some(c) = hand.cards.iter().find(|c| c.rank < Six) {
+            return Err(Undecodable(format!("{}: not in a short deck", c)));
+        }
+
+        let ranks = hand.cards.map(|c| c.rank);
+        let hs = match HandScore::from(hand) {
+            HandScore::HighCard { .. } if ranks == [Six, Seven, Eight, Nine, Ace] => {
+                HandScore::Straight { top_rank: Nine }
+            }
+            HandScore::Flush { .. } if ranks == [Six, Seven, Eight, Nine, Ace] => {
+                HandScore::StraightFlush { top_rank: Nine }
+            }
+            hs => hs,
+        };
+
+        Ok(ShortDeckScore(hs))
+    }
+}
+
+/// Ace-to-five lowball: aces are always low, straights and flushes don't
+/// count, and the lowest hand wins.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(
+///     winning_hands_by(&AceToFive, &["AS 2S 3S 4S 5S", "2D 3H 4C 5D 6S"]),
+///     vec!["AS 2S 3S 4S 5S"],
+/// );
+/// assert_eq!(
+///     winning_hands_by(&AceToFive, &["KS KD 2S 3H 4C", "2D 2H 3C 3D 4S"]),
+///     vec!["KS KD 2S 3H 4C"],
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AceToFive;
+
+/// A hand's ranks, grouped like `tally_ranks` does, so that a lower
+/// `LowballScore` is a lower hand.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LowballScore {
+    /// How many cards of each rank, most first.
+    pub counts: Vec<usize>,
+    pub ranks: Vec<Rank>,
+}
+
+impl RankingRules for AceToFive {
+    type Score = Reverse<LowballScore>;
+
+    fn score(&self, hand: &str) -> Result<Self::Score, Undecodable> {
+        let cards = Hand::try_from(hand)?.cards.map(|c| match c.rank {
+            Rank::Ace => Card {
+                rank: Rank::AceLow,
+                ..c
+            },
+            _ => c,
+        });
+        let (counts, ranks) = tally_ranks(&cards).into_iter().unzip();
+
+        Ok(Reverse(LowballScore { counts, ranks }))
+    }
+}
+
+/// Deuce-to-seven lowball: aces are always high, straights and flushes count
+/// against you, and the lowest hand wins.
+///
+/// ```
+/// # use poker::*;
+/// assert_eq!(
+///     winning_hands_by(&DeuceToSeven, &["AS 2D 3H 4C 5S", "KS 2D 3H 4C 5S"]),
+///     vec!["KS 2D 3H 4C 5S"],
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeuceToSeven;
+
+impl RankingRules for DeuceToSeven {
+    type Score = Reverse<HandScore>;
+
+    fn score(&self, hand: &str) -> Result<Self::Score, Undecodable> {
+        use Rank::*;
+
+        let ace_high = [Ace, Five, Four, Three, Two];
+        let hs = match HandScore::from(Hand::try_from(hand)?) {
+            HandScore::Straight { top_rank: Five } => HandScore::HighCard { ranks: ace_high },
+            HandScore::StraightFlush { top_rank: Five } => HandScore::Flush { ranks: ace_high },
+            hs => hs,
+        };
+
+        Ok(Reverse(hs))
+    }
+}
+
+/// High hand poker where jokers, written `JK`, and any cards of `rank` can
+/// stand for any card. That makes five of a kind the best hand.
+///
+/// ```
+/// # use poker::{*, HandScore::*, Rank::*};
+/// assert_eq!(
+///     Wild::DEUCES.score("2S 2D AH AC KS"),
+///     Ok(WildScore::Hand(FourOfAKind{top_rank: Ace, bottom_rank: King})),
+/// );
+/// assert_eq!(
+///     Wild::JOKERS.score("JK 10S JS QS KS"),
+///     Ok(WildScore::Hand(StraightFlush{top_rank: Ace})),
+/// );
+/// assert_eq!(Wild::JOKERS.score("JK 7S 7D 7H 7C"), Ok(WildScore::FiveOfAKind{rank: Seven}));
+/// assert_eq!(
+///     winning_hands_by(&Wild::DEUCES, &["2S 3S 4S 5S 7S", "AS AD AH AC KC"]),
+///     vec!["2S 3S 4S 5S 7S"],
+/// );
+/// assert!(HighHand.score("JK 7S 7D 7H 7C").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Wild {
+    /// Cards of this rank are wild too, as well as jokers.
+    pub rank: Option<Rank>,
+}
+
+impl Wild {
+    pub const JOKERS: Wild = Wild { rank: None };
+    pub const DEUCES: Wild = Wild {
+        rank: Some(Rank::Two),
+    };
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WildScore {
+    Hand(HandScore),
+    FiveOfAKind { rank: Rank },
+}
+
+impl RankingRules for Wild {
+    type Score = WildScore;
+
+    fn score(&self, hand: &str) -> Result<Self::Score, Undecodable> {
+        let mut wilds = 0;
+        let mut naturals = vec![];
+        let mut seen = HashSet::new();
+        for s in hand.split_ascii_whitespace() {
+            if s == "JK" {
+                wilds += 1;
+                continue;
+            }
+            let card = Card::try_from(s)?;
+            if !seen.insert(card) {
+                return Err(Undecodable(format!(
+                    "{:?}: expected all cards to be different",
+                    hand
+                )));
+            }
+            if Some(card.rank) == self.rank {
+                wilds += 1;
+            } else {
+                naturals.push(card);
+            }
+        }
+
+        if wilds + naturals.len() != 5 {
+            return Err(Undecodable(format!("{:?}: expected to be 5 cards", hand)));
+        }
+
+        let Some(first) = naturals.first().copied() else {
+            return Ok(WildScore::FiveOfAKind { rank: Rank::Ace });
+        };
+        if naturals.iter().all(|c| c.rank == first.rank) {
+            return Ok(WildScore::FiveOfAKind { rank: first.rank });
+        }
+
+        // Try every rank for every wild card. They take the first natural
+        // card's suit, which makes a flush whenever one is possible.
+        let ranks = {
+            use Rank::*;
+            [
+                Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Jack, Queen, King, Ace,
+            ]
+        };
+        let mut best: Option<HandScore> = None;
+        let mut picks = vec![0; wilds];
+        loop {
+            let mut cards = naturals.clone();
+            cards.extend(picks.iter().map(|i| Card {
+                rank: ranks[*i],
+                suit: first.suit,
+            }));
+            let cards: [Card; 5] = cards.try_into().expect("there are 5 cards");
+            best = best.max(Some(HandScore::from(Hand::from(cards))));
+
+            // Next combination of picks, like counting in base 13.
+            let Some(i) = picks.iter().position(|p| *p + 1 < ranks.len()) else {
+                break;
+            };
+            picks[i] += 1;
+            picks[..i].fill(0);
+        }
+
+        Ok(WildScore::Hand(best.expect("there's at least one pick")))
+    }
+}
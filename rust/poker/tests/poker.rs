@@ -14,7 +14,7 @@ fn hs_from<'a>(input: &[&'a str]) -> HashSet<&'a str> {
 ///
 /// Note that the output can be in any order. Here, we use a HashSet to
 /// abstract away the order of outputs.
-fn test<'a, 'b>(input: &[&'a str], expected: &[&'b str]) {
+fn test(input: &[&str], expected: &[&str]) {
     assert_eq!(hs_from(&winning_hands(input)), hs_from(expected))
 }
 
@@ -57,6 +57,11 @@ fn test_high_card_can_be_low_card_in_an_otherwise_tie() {
     test(&["3S 5H 6S 8D 7H", "2S 5D 6D 8C 7S"], &["3S 5H 6S 8D 7H"])
 }
 
+#[test]
+fn test_high_card_compares_the_highest_card_first() {
+    test(&["2C 4D 6H 8S KC", "3C 4D 6H 8S QC"], &["2C 4D 6H 8S KC"])
+}
+
 #[test]
 fn test_one_pair_beats_high_card() {
     test(&["4S 5H 6C 8D KH", "2S 4H 6S 4D JH"], &["2S 4H 6S 4D JH"])
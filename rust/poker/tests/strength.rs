@@ -0,0 +1,52 @@
+use poker::*;
+use std::collections::{BTreeMap, HashSet};
+
+/// Every hand, up to renaming suits: only hands where the suits first show up
+/// in the order clubs, diamonds, hearts, spades.
+fn hands_up_to_suits() -> Vec<Hand> {
+    let deck = Deck::new();
+    let cards = deck.cards();
+
+    let mut hands = vec![];
+    for a in 0..cards.len() {
+        for b in a + 1..cards.len() {
+            for c in b + 1..cards.len() {
+                for d in c + 1..cards.len() {
+                    for e in d + 1..cards.len() {
+                        hands.push(Hand::from([
+                            cards[a], cards[b], cards[c], cards[d], cards[e],
+                        ]));
+                    }
+                }
+            }
+        }
+    }
+    hands.retain(|h| {
+        let mut seen: Vec<Suit> = vec![];
+        for c in h.cards {
+            if !seen.contains(&c.suit) {
+                seen.push(c.suit);
+            }
+        }
+        seen[..] == [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades][..seen.len()]
+    });
+    hands
+}
+
+#[test]
+fn test_strength_orders_every_hand_like_hand_score() {
+    let hands = hands_up_to_suits();
+
+    let mut scores = BTreeMap::new();
+    for h in hands {
+        let strength = HandStrength::from(h);
+        let score = HandScore::from(h);
+        assert_eq!(strength, HandStrength::from(&score), "{}", h);
+        scores.insert(score, strength);
+    }
+
+    assert_eq!(scores.len(), 7462);
+    let strengths: Vec<HandStrength> = scores.into_values().collect();
+    assert!(strengths.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(strengths.iter().collect::<HashSet<_>>().len(), 7462);
+}
@@ -0,0 +1,211 @@
+use poker::*;
+
+fn cards(s: &str) -> Vec<Card> {
+    parse_cards(s).unwrap()
+}
+
+fn nets(settlement: &Settlement) -> Vec<i64> {
+    settlement.players.iter().map(ChipMovement::net).collect()
+}
+
+#[test]
+fn test_heads_up_small_blind_acts_first_only_before_the_flop() {
+    let mut hand = HoldemHand::new(&[("Ann", 100), ("Bob", 100)], 1, 2).unwrap();
+    assert_eq!(hand.to_act(), Some("Ann"));
+    hand.act("Ann", Action::Call).unwrap();
+    assert_eq!(hand.to_act(), Some("Bob"));
+    hand.act("Bob", Action::Check).unwrap();
+
+    hand.deal_board(&cards("2C 7D KH")).unwrap();
+    assert_eq!(hand.to_act(), Some("Bob"));
+}
+
+#[test]
+fn test_big_blind_gets_the_option() {
+    let mut hand = HoldemHand::new(&[("Ann", 100), ("Bob", 100), ("Cy", 100)], 1, 2).unwrap();
+    hand.act("Cy", Action::Call).unwrap();
+    hand.act("Ann", Action::Call).unwrap();
+    assert_eq!(hand.to_act(), Some("Bob"));
+    hand.act("Bob", Action::RaiseTo(6)).unwrap();
+    assert_eq!(hand.to_act(), Some("Cy"));
+}
+
+#[test]
+fn test_illegal_actions() {
+    let mut hand = HoldemHand::new(&[("Ann", 100), ("Bob", 100), ("Cy", 100)], 1, 2).unwrap();
+    let illegal = |r: Result<(), TableError>| matches!(r, Err(TableError::IllegalAction { .. }));
+
+    assert!(matches!(
+        hand.act("Ann", Action::Fold),
+        Err(TableError::OutOfTurn { .. })
+    ));
+    assert!(illegal(hand.act("Cy", Action::Check)));
+    assert!(illegal(hand.act("Cy", Action::Bet(4))));
+    assert!(illegal(hand.act("Cy", Action::RaiseTo(3))));
+    assert!(illegal(hand.act("Cy", Action::RaiseTo(101))));
+    assert_eq!(
+        hand.deal_board(&cards("2C 7D KH")),
+        Err(TableError::BettingNotOver)
+    );
+    assert_eq!(hand.settle(), Err(TableError::HandNotOver));
+}
+
+#[test]
+fn test_an_all_in_short_of_a_full_raise_does_not_reopen_the_betting() {
+    let mut hand = HoldemHand::new(
+        &[("Ann", 1000), ("Bob", 1000), ("Cy", 1000), ("Dee", 25)],
+        5,
+        10,
+    )
+    .unwrap();
+    hand.act("Cy", Action::RaiseTo(20)).unwrap();
+    hand.act("Dee", Action::AllIn).unwrap();
+    hand.act("Ann", Action::Fold).unwrap();
+    hand.act("Bob", Action::Call).unwrap();
+
+    assert_eq!(hand.to_act(), Some("Cy"));
+    assert!(matches!(
+        hand.act("Cy", Action::RaiseTo(60)),
+        Err(TableError::IllegalAction { .. })
+    ));
+    hand.act("Cy", Action::Call).unwrap();
+    assert_eq!(hand.to_act(), None);
+    assert_eq!(hand.pot(), 80);
+}
+
+#[test]
+fn test_an_uncalled_bet_goes_back() {
+    let settlement = replay(
+        "
+        seat Ann 50
+        seat Bob 200
+        blinds 1 2
+        deal Ann AS AH
+        deal Bob 7C 2D
+        Ann all-in
+        Bob all-in
+        flop 3S 8H 9D
+        turn JC
+        river KS
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(settlement.pots.len(), 2);
+    assert_eq!(settlement.pots[0].amount, 100);
+    assert_eq!(settlement.pots[0].winners, vec!["Ann"]);
+    assert_eq!(
+        settlement.pots[0].score,
+        Some(HandScore::from("AS AH KS JC 9D"))
+    );
+    assert_eq!(settlement.pots[1].amount, 150);
+    assert_eq!(settlement.pots[1].winners, vec!["Bob"]);
+    assert_eq!(settlement.pots[1].score, None);
+    assert_eq!(nets(&settlement), vec![50, -50]);
+}
+
+#[test]
+fn test_odd_chips_go_to_the_winner_closest_to_the_small_blind() {
+    let settlement = replay(
+        "
+        seat Ann 100
+        seat Bob 100
+        seat Cy 100
+        blinds 1 2
+        deal Bob 2C 3D
+        deal Cy 2D 3C
+        Cy calls
+        Ann folds
+        Bob checks
+        flop 10H JH QH
+        Bob checks
+        Cy checks
+        turn KH
+        Bob checks
+        Cy checks
+        river AH
+        Bob checks
+        Cy checks
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(settlement.pots[0].winners, vec!["Bob", "Cy"]);
+    assert_eq!(nets(&settlement), vec![-1, 1, 0]);
+}
+
+#[test]
+fn test_replay_reports_the_line_of_the_problem() {
+    let e = replay("seat Ann 100\nseat Bob 100\nblinds 1 2\nAnn dances").unwrap_err();
+    assert_eq!(e.line, 4);
+    assert!(matches!(e.error, TableError::Unreadable(_)));
+
+    let e = replay("seat Ann 100\nseat Bob 100\nblinds 1 2\ndeal Ann AS AH\ndeal Bob AS KD")
+        .unwrap_err();
+    assert_eq!(e.line, 5);
+    assert!(matches!(e.error, TableError::BadCards(_)));
+
+    let e = replay("seat Ann 100\nseat Bob 100\nblinds 1 2\nAnn calls\nBob checks\nturn 2C")
+        .unwrap_err();
+    assert_eq!(
+        e.error,
+        TableError::WrongBoard {
+            street: Street::Preflop,
+            cards: 1
+        }
+    );
+
+    let e = replay("seat Ann 100\nseat Bob 100\nblinds 1 2\nAnn calls").unwrap_err();
+    assert_eq!((e.line, e.error), (4, TableError::HandNotOver));
+}
+
+#[test]
+fn test_a_showdown_needs_hole_cards() {
+    let e = replay(
+        "
+        seat Ann 10
+        seat Bob 10
+        blinds 1 2
+        Ann all-in
+        Bob calls
+        flop 3S 8H 9D
+        turn JC
+        river KS
+        ",
+    )
+    .unwrap_err();
+    assert_eq!(e.error, TableError::MissingHoleCards("Ann".to_string()));
+}
+
+#[test]
+fn test_a_short_big_blind_still_has_to_be_called_in_full() {
+    let settlement = replay(
+        "
+        seat Ann 100
+        seat Bob 1
+        seat Cy 100
+        blinds 1 2
+        deal Ann 7C 2D
+        deal Bob AS AH
+        deal Cy KD KC
+        Cy calls
+        Ann calls
+        flop 3S 8H 9D
+        Ann checks
+        Cy checks
+        turn JC
+        Ann checks
+        Cy checks
+        river 4S
+        Ann checks
+        Cy checks
+        ",
+    )
+    .unwrap();
+
+    let amounts: Vec<u64> = settlement.pots.iter().map(|p| p.amount).collect();
+    assert_eq!(amounts, vec![3, 2]);
+    assert_eq!(settlement.pots[0].winners, vec!["Bob"]);
+    assert_eq!(settlement.pots[1].winners, vec!["Cy"]);
+    assert_eq!(nets(&settlement), vec![-2, 2, 0]);
+}
@@ -0,0 +1,10 @@
+use poker::*;
+
+#[test]
+fn wild_cards_cant_be_repeated() {
+    assert!(Wild::DEUCES.score("2S 2S 5H 6D 7C").is_err());
+    assert!(Wild::DEUCES.score("2S 5H 5H 6D 7C").is_err());
+    assert!(Wild::JOKERS.score("2S 2S 5H 6D 7C").is_err());
+    assert!(Wild::JOKERS.score("JK JK 5H 6D 7C").is_ok());
+    assert!(Wild::DEUCES.score("2S 2D 5H 6D 7C").is_ok());
+}
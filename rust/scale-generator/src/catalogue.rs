@@ -0,0 +1,112 @@
+use crate::{Error, Interval, Mode, Scale, Tonic};
+
+/// A scale with a name, to build on any tonic.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct NamedScale {
+    pub name: &'static str,
+    /// Other names it goes by.
+    pub aliases: &'static [&'static str],
+    /// The steps between its notes, as for `Scale::new`.
+    pub intervals: &'static str,
+    /// The mode whose key signature it's written in.
+    pub mode: Mode,
+}
+
+const fn named(
+    name: &'static str,
+    aliases: &'static [&'static str],
+    intervals: &'static str,
+    mode: Mode,
+) -> NamedScale {
+    NamedScale {
+        name,
+        aliases,
+        intervals,
+        mode,
+    }
+}
+
+/// Every `NamedScale`.
+pub const CATALOGUE: &[NamedScale] = &[
+    named("ionian", &["major"], "MMmMMMm", Mode::Ionian),
+    named("dorian", &[], "MmMMMmM", Mode::Dorian),
+    named("phrygian", &[], "mMMMmMM", Mode::Phrygian),
+    named("lydian", &[], "MMMmMMm", Mode::Lydian),
+    named("mixolydian", &[], "MMmMMmM", Mode::Mixolydian),
+    named(
+        "aeolian",
+        &["minor", "natural minor"],
+        "MmMMmMM",
+        Mode::Aeolian,
+    ),
+    named("locrian", &[], "mMMmMMM", Mode::Locrian),
+    named("harmonic minor", &[], "MmMMmAm", Mode::MINOR),
+    named("melodic minor", &["jazz minor"], "MmMMMMm", Mode::MINOR),
+    named("major pentatonic", &["pentatonic"], "MMAMA", Mode::MAJOR),
+    named("minor pentatonic", &[], "AMMAM", Mode::MINOR),
+    named("blues", &["minor blues"], "AMmmAM", Mode::MINOR),
+    named("whole tone", &["whole-tone"], "MMMMMM", Mode::MAJOR),
+    named(
+        "octatonic",
+        &["whole-half diminished"],
+        "MmMmMmMm",
+        Mode::MAJOR,
+    ),
+    named("half-whole diminished", &[], "mMmMmMmM", Mode::MAJOR),
+];
+
+fn intervals(pattern: &str) -> Result<Vec<Interval>, Error> {
+    pattern.chars().map(Interval::try_from).collect()
+}
+
+impl NamedScale {
+    /// The scale called `name`, or one of its aliases, in any case.
+    pub fn find(name: &str) -> Option<&'static NamedScale> {
+        CATALOGUE.iter().find(|s| {
+            s.name.eq_ignore_ascii_case(name)
+                || s.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// The scales with the intervals in `pattern`.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let found = NamedScale::search("MmMMmAm").unwrap();
+    /// assert_eq!(found[0].name, "harmonic minor");
+    /// assert!(NamedScale::search("MMMMMMM").unwrap().is_empty());
+    /// assert!(NamedScale::search("MmX").is_err());
+    /// ```
+    pub fn search(pattern: &str) -> Result<Vec<&'static NamedScale>, Error> {
+        let pattern = intervals(pattern)?;
+        Ok(CATALOGUE
+            .iter()
+            .filter(|s| intervals(s.intervals).as_ref() == Ok(&pattern))
+            .collect())
+    }
+
+    /// This scale on `tonic`, in either case, spelled in the notation of
+    /// the scale's mode on it.
+    pub fn on(&self, tonic: &str) -> Result<Scale, Error> {
+        Scale::with_tonic(Tonic::in_mode(tonic, self.mode)?, self.intervals)
+    }
+}
+
+impl Scale {
+    /// The `NamedScale` called `name`, on `tonic`.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let scale = Scale::named("Mixolydian", "Eb").unwrap();
+    /// assert_eq!(scale.enumerate(), ["Eb", "F", "G", "Ab", "Bb", "C", "Db", "Eb"]);
+    ///
+    /// let scale = Scale::named("blues", "e").unwrap();
+    /// assert_eq!(scale.enumerate(), ["E", "G", "A", "A#", "B", "D", "E"]);
+    /// assert!(Scale::named("bebop", "C").is_err());
+    /// ```
+    pub fn named(name: &str, tonic: &str) -> Result<Scale, Error> {
+        NamedScale::find(name)
+            .ok_or_else(|| format!("{:?}: not a scale in the catalogue", name))?
+            .on(tonic)
+    }
+}
@@ -0,0 +1,251 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Error, Letter, Note, Scale, SpelledNote};
+
+/// What kind of chord a chord is, as the notes it stacks on its root.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Quality {
+    pub name: &'static str,
+    /// What follows the root in a chord symbol.
+    pub symbol: &'static str,
+    /// Other ways of writing `symbol`.
+    pub aliases: &'static [&'static str],
+    /// Each note above the root, as the degree of the root's scale it's
+    /// spelled as, and the semitones it is above the root.
+    pub tones: &'static [(u8, u8)],
+}
+
+const fn quality(
+    name: &'static str,
+    symbol: &'static str,
+    aliases: &'static [&'static str],
+    tones: &'static [(u8, u8)],
+) -> Quality {
+    Quality {
+        name,
+        symbol,
+        aliases,
+        tones,
+    }
+}
+
+/// Every `Quality`.
+pub const QUALITIES: &[Quality] = &[
+    quality("major", "", &["M", "maj"], &[(3, 4), (5, 7)]),
+    quality("minor", "m", &["min", "-"], &[(3, 3), (5, 7)]),
+    quality("diminished", "dim", &["o"], &[(3, 3), (5, 6)]),
+    quality("augmented", "aug", &["+"], &[(3, 4), (5, 8)]),
+    quality("suspended second", "sus2", &[], &[(2, 2), (5, 7)]),
+    quality("suspended fourth", "sus4", &["sus"], &[(4, 5), (5, 7)]),
+    quality("dominant seventh", "7", &[], &[(3, 4), (5, 7), (7, 10)]),
+    quality("major seventh", "maj7", &["M7"], &[(3, 4), (5, 7), (7, 11)]),
+    quality(
+        "minor seventh",
+        "m7",
+        &["min7", "-7"],
+        &[(3, 3), (5, 7), (7, 10)],
+    ),
+    quality(
+        "minor major seventh",
+        "mMaj7",
+        &["m(maj7)", "minMaj7"],
+        &[(3, 3), (5, 7), (7, 11)],
+    ),
+    quality(
+        "half-diminished seventh",
+        "m7b5",
+        &["ø", "ø7"],
+        &[(3, 3), (5, 6), (7, 10)],
+    ),
+    quality(
+        "diminished seventh",
+        "dim7",
+        &["o7"],
+        &[(3, 3), (5, 6), (7, 9)],
+    ),
+    quality(
+        "augmented seventh",
+        "aug7",
+        &["+7", "7#5"],
+        &[(3, 4), (5, 8), (7, 10)],
+    ),
+    quality(
+        "augmented major seventh",
+        "maj7#5",
+        &["+M7", "augMaj7"],
+        &[(3, 4), (5, 8), (7, 11)],
+    ),
+];
+
+impl Quality {
+    /// The quality written `symbol`, or one of its aliases.
+    pub fn find(symbol: &str) -> Option<&'static Quality> {
+        QUALITIES
+            .iter()
+            .find(|q| q.symbol == symbol || q.aliases.contains(&symbol))
+    }
+}
+
+fn transpose(note: Note, semitones: u8) -> Note {
+    ((note as u8 + semitones) % 12)
+        .try_into()
+        .expect("less than an octave is a note")
+}
+
+/// A chord: its root, its quality, and the note in the bass if it's not the
+/// root.
+///
+/// ```
+/// # use scale_generator::*;
+/// let names = |symbol: &str| -> Vec<String> {
+///     let chord: Chord = symbol.parse().unwrap();
+///     chord.notes().iter().map(|n| n.to_string()).collect()
+/// };
+/// assert_eq!(names("Cmaj7"), ["C", "E", "G", "B"]);
+/// assert_eq!(names("F#m7b5"), ["F#", "A", "C", "E"]);
+/// assert_eq!(names("Bb7/D"), ["D", "F", "Ab", "Bb"]);
+/// assert_eq!(names("C/F"), ["F", "C", "E", "G"]);
+///
+/// assert_eq!(
+///     "Cmaj9".parse::<Chord>(),
+///     Err(r#""Cmaj9": "maj9" isn't a chord quality"#.to_string()),
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Chord {
+    pub root: SpelledNote,
+    pub quality: &'static Quality,
+    pub bass: Option<SpelledNote>,
+}
+
+impl Chord {
+    pub fn new(root: SpelledNote, quality: &'static Quality) -> Chord {
+        Chord {
+            root,
+            quality,
+            bass: None,
+        }
+    }
+
+    /// The notes from the root up, or from the bass if there is one: an
+    /// inversion if it's in the chord, and added below it if not.
+    pub fn notes(&self) -> Vec<SpelledNote> {
+        let mut notes = vec![self.root];
+        for (degree, semitones) in self.quality.tones {
+            let letter = (1..*degree).fold(self.root.letter, |l, _| l.next());
+            let note = transpose(self.root.note(), *semitones);
+            notes.push(SpelledNote::with_letter(note, letter));
+        }
+
+        if let Some(bass) = self.bass {
+            match notes.iter().position(|n| n.note() == bass.note()) {
+                Some(i) => {
+                    notes.rotate_left(i);
+                    notes[0] = bass;
+                }
+                None => notes.insert(0, bass),
+            }
+        }
+        notes
+    }
+}
+
+/// A root, a `Quality`'s symbol, and an optional `/` and bass note.
+impl FromStr for Chord {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason: String| format!("{:?}: {}", s, reason);
+        let (chord, bass) = match s.split_once('/') {
+            Some((chord, bass)) => {
+                let bass = bass
+                    .parse()
+                    .map_err(|_| error(format!("{:?} isn't a bass note", bass)))?;
+                (chord, Some(bass))
+            }
+            None => (s, None),
+        };
+
+        if !chord.starts_with(|c| Letter::try_from(c).is_ok()) {
+            return Err(error("a chord starts with its root".to_string()));
+        }
+        let rest = &chord[1..];
+        let sharps = rest.len() - rest.trim_start_matches('#').len();
+        let flats = rest.len() - rest.trim_start_matches('b').len();
+        let (root, symbol) = chord.split_at(1 + sharps.max(flats));
+        let root = root
+            .parse()
+            .map_err(|_| error(format!("{:?} isn't a root", root)))?;
+        let quality = Quality::find(symbol)
+            .ok_or_else(|| error(format!("{:?} isn't a chord quality", symbol)))?;
+        Ok(Chord {
+            root,
+            quality,
+            bass,
+        })
+    }
+}
+
+/// Its symbol.
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.root, self.quality.symbol)?;
+        if let Some(bass) = self.bass {
+            write!(f, "/{}", bass)?;
+        }
+        Ok(())
+    }
+}
+
+impl Scale {
+    /// The chord on `degree` of the scale, counting from 1, that stacks
+    /// `size` of the scale's notes a third apart: 3 for a triad, 4 for a
+    /// seventh chord.
+    fn stacked(&self, degree: usize, size: usize) -> Result<Chord, Error> {
+        let notes = self.spell();
+        if notes.len() != 8 {
+            return Err("only scales of seven notes have chords in thirds".to_string());
+        }
+        if !(1..=7).contains(&degree) {
+            return Err(format!("{}: not a degree of the scale", degree));
+        }
+
+        let root = notes[degree - 1];
+        let tones: Vec<(u8, u8)> = (1..size)
+            .map(|i| {
+                let tone = notes[(degree - 1 + 2 * i) % 7];
+                let semitones = (tone.note() as u8 + 12 - root.note() as u8) % 12;
+                (2 * i as u8 + 1, semitones)
+            })
+            .collect();
+        QUALITIES
+            .iter()
+            .find(|q| q.tones == tones)
+            .map(|quality| Chord::new(root, quality))
+            .ok_or_else(|| format!("{}: no quality has the notes {:?}", degree, tones))
+    }
+
+    /// The triad on `degree` of the scale, counting from 1.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let scale = Scale::named("harmonic minor", "a").unwrap();
+    /// let triads: Vec<String> = (1..=7).map(|d| scale.triad(d).unwrap().to_string()).collect();
+    /// assert_eq!(triads, ["Am", "Bdim", "Caug", "Dm", "E", "F", "G#dim"]);
+    /// ```
+    pub fn triad(&self, degree: usize) -> Result<Chord, Error> {
+        self.stacked(degree, 3)
+    }
+
+    /// The seventh chord on `degree` of the scale, counting from 1.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let scale = Scale::named("major", "Eb").unwrap();
+    /// let sevenths: Vec<String> = (1..=7).map(|d| scale.seventh(d).unwrap().to_string()).collect();
+    /// assert_eq!(sevenths, ["Ebmaj7", "Fm7", "Gm7", "Abmaj7", "Bb7", "Cm7", "Dm7b5"]);
+    /// ```
+    pub fn seventh(&self, degree: usize) -> Result<Chord, Error> {
+        self.stacked(degree, 4)
+    }
+}
@@ -1,4 +1,14 @@
-use std::str::FromStr;
+use std::{cmp::Ordering, iter, str::FromStr};
+
+mod catalogue;
+mod chord;
+mod pitch;
+mod spelling;
+
+pub use catalogue::*;
+pub use chord::*;
+pub use pitch::*;
+pub use spelling::*;
 
 type Error = String;
 
@@ -67,11 +77,6 @@ impl FromStr for Note {
     }
 }
 
-pub struct Tonic {
-    pub note: Note,
-    pub notation: TonicNotation,
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 #[repr(u8)]
 pub enum TonicNotation {
@@ -116,125 +121,133 @@ impl TonicNotation {
     }
 }
 
+/// The seven modes of the major scale, each a rotation of it.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Mode {
+    Ionian,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Aeolian,
+    Locrian,
+}
+
+impl Mode {
+    pub const MAJOR: Mode = Mode::Ionian;
+    pub const MINOR: Mode = Mode::Aeolian;
+
+    /// How many more sharps, or fewer flats, a key in this mode has than the
+    /// major key on the same tonic.
+    fn fifths(&self) -> i8 {
+        match self {
+            Mode::Lydian => 1,
+            Mode::Ionian => 0,
+            Mode::Mixolydian => -1,
+            Mode::Dorian => -2,
+            Mode::Aeolian => -3,
+            Mode::Phrygian => -4,
+            Mode::Locrian => -5,
+        }
+    }
+}
+
+/// The number of sharps in a key, or of flats if it's negative: how far the
+/// key is round the circle of fifths from C major.
+///
+/// Keys past seven sharps or flats are theoretical, but still have a
+/// signature, so `G#` major has 8 sharps.
+///
+/// ```
+/// # use scale_generator::*;
+/// assert_eq!(KeySignature::of("Eb", Mode::MAJOR), Ok(KeySignature(-3)));
+/// assert_eq!(KeySignature::of("f#", Mode::MINOR), Ok(KeySignature(3)));
+/// assert_eq!(KeySignature::of("D", Mode::Dorian).unwrap().notation(), None);
+/// assert_eq!(KeySignature::of("G#", Mode::MAJOR).unwrap().notation(), Some(TonicNotation::Sharp));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct KeySignature(pub i8);
+
+impl KeySignature {
+    /// The signature of the key in `mode` on `tonic`: a letter in either
+    /// case, and an optional `#` or `b`.
+    pub fn of(tonic: &str, mode: Mode) -> Result<KeySignature, Error> {
+        let tonic = parse_tonic(tonic)?;
+        Ok(KeySignature(
+            tonic.letter.fifths() + 7 * tonic.accidental + mode.fifths(),
+        ))
+    }
+
+    /// How the key's accidentals are written, or `None` if it has none and
+    /// either would do.
+    pub fn notation(&self) -> Option<TonicNotation> {
+        match self.0.cmp(&0) {
+            Ordering::Greater => Some(TonicNotation::Sharp),
+            Ordering::Less => Some(TonicNotation::Flat),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+/// `s` as a tonic: a letter in either case, and an optional `#` or `b`.
+fn parse_tonic(s: &str) -> Result<SpelledNote, Error> {
+    s.parse::<SpelledNote>()
+        .ok()
+        .filter(|n| n.accidental.abs() <= 1)
+        .ok_or_else(|| format!("{:?}: not a note", s))
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Tonic {
+    pub note: Note,
+    /// The letter it's spelled with.
+    pub letter: Letter,
+    pub notation: TonicNotation,
+}
+
+impl Tonic {
+    /// `s` as the tonic of a key in `mode`, written in the notation of the
+    /// key's signature. Where the key has no sharps or flats, that's sharps;
+    /// set `notation` to write it in flats instead.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let tonic = Tonic::in_mode("g", Mode::Dorian).unwrap();
+    /// assert_eq!(
+    ///     tonic,
+    ///     Tonic { note: Note::G, letter: Letter::G, notation: TonicNotation::Flat },
+    /// );
+    ///
+    /// let c = Tonic::in_mode("C", Mode::MAJOR).unwrap();
+    /// assert_eq!(c.notation, TonicNotation::Sharp);
+    /// let flat_c = Tonic { notation: TonicNotation::Flat, ..c };
+    /// let scale = Scale::with_tonic(flat_c, "mmmmmmmmmmmm").unwrap();
+    /// assert_eq!(scale.enumerate()[1], "Db");
+    /// ```
+    pub fn in_mode(s: &str, mode: Mode) -> Result<Tonic, Error> {
+        let spelling = parse_tonic(s)?;
+        let notation = KeySignature::of(s, mode)?
+            .notation()
+            .unwrap_or(TonicNotation::Sharp);
+        Ok(Tonic {
+            note: spelling.note(),
+            letter: spelling.letter,
+            notation,
+        })
+    }
+
+    pub fn spelling(&self) -> SpelledNote {
+        SpelledNote::with_letter(self.note, self.letter)
+    }
+}
+
+/// An upper case tonic is major, and a lower case one minor.
 impl FromStr for Tonic {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "A" => Ok(Tonic {
-                note: Note::A,
-                notation: TonicNotation::Sharp,
-            }),
-            "A#" => unimplemented!(),
-            "Bb" => Ok(Tonic {
-                note: Note::ASharp,
-                notation: TonicNotation::Flat,
-            }),
-            "B" => Ok(Tonic {
-                note: Note::B,
-                notation: TonicNotation::Sharp,
-            }),
-            "C" => Ok(Tonic {
-                note: Note::C,
-                notation: TonicNotation::Sharp,
-            }), // Ambivalent
-            "C#" => unimplemented!(),
-            "Db" => Ok(Tonic {
-                note: Note::CSharp,
-                notation: TonicNotation::Flat,
-            }),
-            "D" => Ok(Tonic {
-                note: Note::D,
-                notation: TonicNotation::Sharp,
-            }),
-            "D#" => unimplemented!(),
-            "Eb" => Ok(Tonic {
-                note: Note::DSharp,
-                notation: TonicNotation::Flat,
-            }),
-            "E" => Ok(Tonic {
-                note: Note::E,
-                notation: TonicNotation::Sharp,
-            }),
-            "F" => Ok(Tonic {
-                note: Note::F,
-                notation: TonicNotation::Flat,
-            }),
-            "F#" => Ok(Tonic {
-                note: Note::FSharp,
-                notation: TonicNotation::Sharp,
-            }),
-            "Gb" => Ok(Tonic {
-                note: Note::FSharp,
-                notation: TonicNotation::Flat,
-            }),
-            "G" => Ok(Tonic {
-                note: Note::G,
-                notation: TonicNotation::Sharp,
-            }),
-            "G#" => unimplemented!(),
-            "Ab" => Ok(Tonic {
-                note: Note::GSharp,
-                notation: TonicNotation::Flat,
-            }),
-            "a" => Ok(Tonic {
-                note: Note::A,
-                notation: TonicNotation::Sharp,
-            }), // Ambivalent
-            "a#" => unimplemented!(),
-            "bb" => Ok(Tonic {
-                note: Note::ASharp,
-                notation: TonicNotation::Flat,
-            }),
-            "b" => Ok(Tonic {
-                note: Note::B,
-                notation: TonicNotation::Sharp,
-            }),
-            "c" => Ok(Tonic {
-                note: Note::C,
-                notation: TonicNotation::Flat,
-            }),
-            "c#" => Ok(Tonic {
-                note: Note::CSharp,
-                notation: TonicNotation::Sharp,
-            }),
-            "db" => unimplemented!(),
-            "d" => Ok(Tonic {
-                note: Note::D,
-                notation: TonicNotation::Flat,
-            }),
-            "d#" => Ok(Tonic {
-                note: Note::DSharp,
-                notation: TonicNotation::Sharp,
-            }),
-            "eb" => Ok(Tonic {
-                note: Note::DSharp,
-                notation: TonicNotation::Flat,
-            }),
-            "e" => Ok(Tonic {
-                note: Note::E,
-                notation: TonicNotation::Sharp,
-            }),
-            "f" => Ok(Tonic {
-                note: Note::F,
-                notation: TonicNotation::Flat,
-            }),
-            "f#" => Ok(Tonic {
-                note: Note::FSharp,
-                notation: TonicNotation::Sharp,
-            }),
-            "gb" => unimplemented!(),
-            "g" => Ok(Tonic {
-                note: Note::G,
-                notation: TonicNotation::Flat,
-            }),
-            "g#" => Ok(Tonic {
-                note: Note::GSharp,
-                notation: TonicNotation::Sharp,
-            }),
-            "ab" => unimplemented!(),
-            _ => Err(format!("{:?}: not a note", s)),
-        }
+        let minor = s.starts_with(|c: char| c.is_ascii_lowercase());
+        Tonic::in_mode(s, if minor { Mode::MINOR } else { Mode::MAJOR })
     }
 }
 
@@ -273,8 +286,12 @@ pub struct Scale {
 
 impl Scale {
     pub fn new(tonic: &str, intervals: &str) -> Result<Scale, Error> {
+        Self::with_tonic(tonic.parse()?, intervals)
+    }
+
+    pub fn with_tonic(tonic: Tonic, intervals: &str) -> Result<Scale, Error> {
         Ok(Scale {
-            tonic: tonic.parse()?,
+            tonic,
             intervals: intervals
                 .chars()
                 .map(Interval::try_from)
@@ -286,17 +303,60 @@ impl Scale {
         Self::new(tonic, "mmmmmmmmmmmm")
     }
 
-    pub fn enumerate(&self) -> Vec<String> {
-        let note = self.tonic.note;
-        let notation = self.tonic.notation;
+    fn notes(&self) -> Vec<Note> {
         self.intervals
             .iter()
-            .fold(vec![note], |mut notes, next| {
+            .fold(vec![self.tonic.note], |mut notes, next| {
                 notes.push(next.advance(*notes.last().expect("notes can't be empty")));
                 notes
             })
-            .iter()
-            .map(|n| notation.display(*n))
-            .collect()
+    }
+
+    pub fn enumerate(&self) -> Vec<String> {
+        let notation = self.tonic.notation;
+        self.notes().iter().map(|n| notation.display(*n)).collect()
+    }
+
+    /// The notes spelled with letters. A scale of seven notes uses each
+    /// letter once, with as many sharps or flats as that takes, where
+    /// `enumerate` writes every note in the tonic's notation. Other scales
+    /// are spelled in the tonic's notation too.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let spelled = |scale: Scale| -> Vec<String> {
+    ///     scale.spell().iter().map(|n| n.to_string()).collect()
+    /// };
+    ///
+    /// let c_sharp = Scale::new("C#", "MMmMMMm").unwrap();
+    /// assert_eq!(c_sharp.enumerate(), ["C#", "D#", "F", "F#", "G#", "A#", "C", "C#"]);
+    /// assert_eq!(spelled(c_sharp), ["C#", "D#", "E#", "F#", "G#", "A#", "B#", "C#"]);
+    ///
+    /// let harmonic = Scale::new("d", "MmMMmAm").unwrap();
+    /// assert_eq!(spelled(harmonic), ["D", "E", "F", "G", "A", "Bb", "C#", "D"]);
+    ///
+    /// let whole_tone = Scale::new("Db", "MMMMMM").unwrap();
+    /// assert_eq!(spelled(whole_tone), ["Db", "Eb", "F", "G", "A", "B", "Db"]);
+    /// ```
+    pub fn spell(&self) -> Vec<SpelledNote> {
+        let tonic = self.tonic.spelling();
+        let notes = self.notes();
+        if self.intervals.len() == 7 {
+            iter::successors(Some(tonic.letter), |l| Some(l.next()))
+                .zip(notes)
+                .map(|(letter, note)| SpelledNote::with_letter(note, letter))
+                .collect()
+        } else {
+            notes
+                .into_iter()
+                .map(|n| {
+                    if n == tonic.note() {
+                        tonic
+                    } else {
+                        SpelledNote::with_notation(n, self.tonic.notation)
+                    }
+                })
+                .collect()
+        }
     }
 }
@@ -0,0 +1,163 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::{Error, Letter, Note, Scale, SpelledNote, TonicNotation};
+
+/// What pitch concert A, `A4`, is tuned to, and every other pitch with it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Tuning {
+    /// In Hz.
+    pub a4: f64,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Tuning { a4: 440.0 }
+    }
+}
+
+/// A spelled note in a particular octave. Octaves go from C to B, and
+/// middle C is `C4`. The octave goes by the letter, so `B#3` sounds the same
+/// as `C4`, and `Cb4` as `B3`.
+///
+/// ```
+/// # use scale_generator::*;
+/// let middle_c: Pitch = "C4".parse().unwrap();
+/// assert_eq!(middle_c.midi(), Some(60));
+/// assert_eq!(Pitch::from_midi(70).unwrap().to_string(), "A#4");
+/// assert_eq!("A4".parse::<Pitch>().unwrap().frequency(&Tuning::default()), 440.0);
+///
+/// let baroque = Tuning { a4: 415.0 };
+/// let a3: Pitch = "A3".parse().unwrap();
+/// assert!((a3.frequency(&baroque) - 207.5).abs() < 1e-9);
+///
+/// let b_sharp: Pitch = "B#3".parse().unwrap();
+/// assert_eq!(b_sharp.midi(), middle_c.midi());
+/// assert_eq!(b_sharp.to_string(), "B#3");
+/// assert_eq!("Cb4".parse::<Pitch>().unwrap().sounds_as(), Note::B);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Pitch {
+    pub note: SpelledNote,
+    /// The octave of the note's letter.
+    pub octave: i8,
+}
+
+/// Semitones from `C` up to `letter`.
+fn above_c(letter: Letter) -> i32 {
+    (letter.natural() as i32 + 9) % 12
+}
+
+impl Pitch {
+    pub fn new(note: SpelledNote, octave: i8) -> Pitch {
+        Pitch { note, octave }
+    }
+
+    pub fn sounds_as(&self) -> Note {
+        self.note.note()
+    }
+
+    /// Semitones above `C0`.
+    fn semitones(&self) -> i32 {
+        12 * self.octave as i32 + above_c(self.note.letter) + self.note.accidental as i32
+    }
+
+    /// `note`, in the octave that puts it `semitones` above `C0`, if there's
+    /// an `i8` for it.
+    fn spelled(note: SpelledNote, semitones: i32) -> Option<Pitch> {
+        let natural = semitones - note.accidental as i32 - above_c(note.letter);
+        let octave = i8::try_from(natural.div_euclid(12)).ok()?;
+        Some(Pitch { note, octave })
+    }
+
+    /// The pitch `semitones` above `C0`, spelled with sharps.
+    fn from_semitones(semitones: i32) -> Pitch {
+        let note = ((semitones + 3).rem_euclid(12) as u8)
+            .try_into()
+            .expect("less than an octave is a note");
+        let note = SpelledNote::with_notation(note, TonicNotation::Sharp);
+        Pitch::spelled(note, semitones).expect("MIDI notes have octaves")
+    }
+
+    /// The MIDI note number, if it has one: from 0 for `C-1` to 127 for `G9`.
+    pub fn midi(&self) -> Option<u8> {
+        u8::try_from(self.semitones() + 12)
+            .ok()
+            .filter(|n| *n <= 127)
+    }
+
+    /// Spelled with sharps.
+    pub fn from_midi(n: u8) -> Result<Pitch, Error> {
+        if n > 127 {
+            return Err(format!("{}: not a MIDI note number", n));
+        }
+        Ok(Pitch::from_semitones(n as i32 - 12))
+    }
+
+    /// In Hz, in equal temperament.
+    pub fn frequency(&self, tuning: &Tuning) -> f64 {
+        let a4 = Pitch::new(SpelledNote::with_letter(Note::A, Letter::A), 4);
+        let from_a4 = self.semitones() - a4.semitones();
+        tuning.a4 * 2f64.powf(from_a4 as f64 / 12.0)
+    }
+}
+
+/// A note as `SpelledNote` parses it, then its letter's octave.
+impl FromStr for Pitch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let not_a_pitch = || format!("{:?}: not a pitch", s);
+        let split = s
+            .find(|c: char| c.is_ascii_digit() || c == '-')
+            .ok_or_else(not_a_pitch)?;
+        let (note, octave) = s.split_at(split);
+        Ok(Pitch {
+            note: note.parse().map_err(|_| not_a_pitch())?,
+            octave: octave.parse().map_err(|_| not_a_pitch())?,
+        })
+    }
+}
+
+impl fmt::Display for Pitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.note, self.octave)
+    }
+}
+
+impl Scale {
+    /// The scale once for each of `octaves`, starting from the tonic in the
+    /// first, and ending on the tonic after the last, spelled as `spell`
+    /// spells it. It's an error if the last tonic's octave is past `i8`.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// let scale = Scale::named("major pentatonic", "G").unwrap();
+    /// let pitches: Vec<String> = scale
+    ///     .pitches(3..=4)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|p| p.to_string())
+    ///     .collect();
+    /// assert_eq!(
+    ///     pitches,
+    ///     ["G3", "A3", "B3", "D4", "E4", "G4", "A4", "B4", "D5", "E5", "G5"],
+    /// );
+    /// ```
+    pub fn pitches(&self, octaves: RangeInclusive<i8>) -> Result<Vec<Pitch>, Error> {
+        if octaves.is_empty() {
+            return Ok(vec![]);
+        }
+        let out_of_range = || format!("{:?}: octaves out of range", octaves);
+        let spelled = self.spell();
+        let tonic = Pitch::new(spelled[0], *octaves.start());
+        let mut semitones = tonic.semitones();
+        let mut pitches = vec![tonic];
+        for _ in octaves.clone() {
+            for (interval, note) in self.intervals.iter().zip(&spelled[1..]) {
+                semitones += *interval as i32;
+                pitches.push(Pitch::spelled(*note, semitones).ok_or_else(out_of_range)?);
+            }
+        }
+        Ok(pitches)
+    }
+}
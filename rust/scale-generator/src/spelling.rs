@@ -0,0 +1,175 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Error, Note, TonicNotation};
+
+/// A note's letter name.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Letter {
+    C,
+    D,
+    E,
+    F,
+    G,
+    A,
+    B,
+}
+
+impl Letter {
+    const ALL: [Letter; 7] = [
+        Letter::C,
+        Letter::D,
+        Letter::E,
+        Letter::F,
+        Letter::G,
+        Letter::A,
+        Letter::B,
+    ];
+
+    /// The letter after this one, going round from `B` to `C`.
+    pub fn next(&self) -> Letter {
+        Letter::ALL[(*self as usize + 1) % 7]
+    }
+
+    /// The note the letter names on its own.
+    pub fn natural(&self) -> Note {
+        match self {
+            Letter::C => Note::C,
+            Letter::D => Note::D,
+            Letter::E => Note::E,
+            Letter::F => Note::F,
+            Letter::G => Note::G,
+            Letter::A => Note::A,
+            Letter::B => Note::B,
+        }
+    }
+
+    /// How far the letter is round the circle of fifths from `C`.
+    pub(crate) fn fifths(&self) -> i8 {
+        match self {
+            Letter::F => -1,
+            Letter::C => 0,
+            Letter::G => 1,
+            Letter::D => 2,
+            Letter::A => 3,
+            Letter::E => 4,
+            Letter::B => 5,
+        }
+    }
+}
+
+impl TryFrom<char> for Letter {
+    type Error = String;
+
+    /// In either case.
+    fn try_from(c: char) -> Result<Letter, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'C' => Ok(Letter::C),
+            'D' => Ok(Letter::D),
+            'E' => Ok(Letter::E),
+            'F' => Ok(Letter::F),
+            'G' => Ok(Letter::G),
+            'A' => Ok(Letter::A),
+            'B' => Ok(Letter::B),
+            _ => Err(format!("{:?}: not a letter", c)),
+        }
+    }
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A note written as a letter and an accidental, so that `E#` and `F` are
+/// different notes that sound the same.
+///
+/// ```
+/// # use scale_generator::*;
+/// let e_sharp: SpelledNote = "E#".parse().unwrap();
+/// assert_eq!(e_sharp, SpelledNote { letter: Letter::E, accidental: 1 });
+/// assert_eq!(e_sharp.note(), Note::F);
+///
+/// let a_double_flat: SpelledNote = "Abb".parse().unwrap();
+/// assert_eq!(a_double_flat.note(), Note::G);
+/// assert_eq!(a_double_flat.to_string(), "Abb");
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct SpelledNote {
+    pub letter: Letter,
+    /// The number of sharps, or of flats if it's negative.
+    pub accidental: i8,
+}
+
+impl SpelledNote {
+    /// `note` with `letter`, sharpened or flattened as far as it takes, the
+    /// shorter way round.
+    ///
+    /// ```
+    /// # use scale_generator::*;
+    /// assert_eq!(SpelledNote::with_letter(Note::B, Letter::C).to_string(), "Cb");
+    /// assert_eq!(SpelledNote::with_letter(Note::A, Letter::G).to_string(), "G##");
+    /// ```
+    pub fn with_letter(note: Note, letter: Letter) -> SpelledNote {
+        let up = (note as i8 - letter.natural() as i8).rem_euclid(12);
+        SpelledNote {
+            letter,
+            accidental: if up > 6 { up - 12 } else { up },
+        }
+    }
+
+    /// `note` as `notation` writes it: with a single sharp or flat, if any.
+    pub fn with_notation(note: Note, notation: TonicNotation) -> SpelledNote {
+        notation
+            .display(note)
+            .parse()
+            .expect("notations write notes")
+    }
+
+    /// The note it sounds as.
+    pub fn note(&self) -> Note {
+        let semitones = (self.letter.natural() as i32 + self.accidental as i32).rem_euclid(12);
+        (semitones as u8)
+            .try_into()
+            .expect("less than an octave is a note")
+    }
+}
+
+/// A letter in either case, then up to two `#`s, or two `b`s.
+impl FromStr for SpelledNote {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let not_a_note = || format!("{:?}: not a note", s);
+        let mut chars = s.chars();
+        let letter = chars
+            .next()
+            .and_then(|c| Letter::try_from(c).ok())
+            .ok_or_else(not_a_note)?;
+        let accidentals = chars.as_str();
+        let count = i8::try_from(accidentals.len())
+            .ok()
+            .filter(|&count| count <= 2)
+            .ok_or_else(not_a_note)?;
+        let accidental = if accidentals.chars().all(|c| c == '#') {
+            count
+        } else if accidentals.chars().all(|c| c == 'b') {
+            -count
+        } else {
+            return Err(not_a_note());
+        };
+        Ok(SpelledNote { letter, accidental })
+    }
+}
+
+impl fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.accidental < 0 { "b" } else { "#" };
+        write!(
+            f,
+            "{}{}",
+            self.letter,
+            sign.repeat(self.accidental.unsigned_abs() as usize)
+        )
+    }
+}
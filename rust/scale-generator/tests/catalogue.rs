@@ -0,0 +1,91 @@
+use scale_generator::*;
+
+fn semitones(intervals: &str) -> u32 {
+    intervals
+        .chars()
+        .map(|c| Interval::try_from(c).unwrap() as u32)
+        .sum()
+}
+
+#[test]
+fn every_scale_spans_an_octave() {
+    for scale in CATALOGUE {
+        assert_eq!(semitones(scale.intervals), 12, "{}", scale.name);
+    }
+}
+
+#[test]
+fn the_modes_are_rotations_of_the_major_scale() {
+    let major = "MMmMMMm";
+    let modes = [
+        "ionian",
+        "dorian",
+        "phrygian",
+        "lydian",
+        "mixolydian",
+        "aeolian",
+        "locrian",
+    ];
+    for (i, mode) in modes.into_iter().enumerate() {
+        let rotated = format!("{}{}", &major[i..], &major[..i]);
+        assert_eq!(NamedScale::find(mode).unwrap().intervals, rotated);
+    }
+}
+
+#[test]
+fn every_scale_can_be_built_on_any_tonic() {
+    for scale in CATALOGUE {
+        for tonic in ["C", "c#", "Db", "f", "A#", "gb"] {
+            let notes = scale.on(tonic).unwrap().enumerate();
+            assert_eq!(notes.len(), scale.intervals.len() + 1);
+            assert_eq!(notes.first(), notes.last());
+        }
+    }
+}
+
+#[test]
+fn names_and_aliases_in_any_case() {
+    assert_eq!(
+        Scale::named("Natural Minor", "a").unwrap().enumerate(),
+        Scale::new("a", "MmMMmMM").unwrap().enumerate()
+    );
+    assert_eq!(NamedScale::find("MAJOR").unwrap().name, "ionian");
+    assert_eq!(
+        Scale::named("bebop", "C").err(),
+        Some(r#""bebop": not a scale in the catalogue"#.to_string())
+    );
+}
+
+#[test]
+fn searching_by_pattern() {
+    let names = |pattern| -> Vec<&str> {
+        NamedScale::search(pattern)
+            .unwrap()
+            .iter()
+            .map(|s| s.name)
+            .collect()
+    };
+    assert_eq!(names("MmMMmMM"), ["aeolian"]);
+    assert_eq!(names("MMAMA"), ["major pentatonic"]);
+    assert_eq!(names("mMmMmMmM"), ["half-whole diminished"]);
+    assert_eq!(
+        NamedScale::search("MMq"),
+        Err("'q': not an interval".to_string())
+    );
+}
+
+#[test]
+fn named_scales_follow_their_key_signature() {
+    assert_eq!(
+        Scale::named("locrian", "B").unwrap().enumerate(),
+        ["B", "C", "D", "E", "F", "G", "A", "B"]
+    );
+    assert_eq!(
+        Scale::named("dorian", "G").unwrap().enumerate(),
+        ["G", "A", "Bb", "C", "D", "E", "F", "G"]
+    );
+    assert_eq!(
+        Scale::named("harmonic minor", "D").unwrap().enumerate(),
+        Scale::new("d", "MmMMmAm").unwrap().enumerate()
+    );
+}
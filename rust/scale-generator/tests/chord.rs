@@ -0,0 +1,100 @@
+use scale_generator::*;
+
+fn names(notes: &[SpelledNote]) -> Vec<String> {
+    notes.iter().map(SpelledNote::to_string).collect()
+}
+
+fn parse(symbol: &str) -> Vec<String> {
+    names(&symbol.parse::<Chord>().unwrap().notes())
+}
+
+#[test]
+fn triads() {
+    assert_eq!(parse("C"), ["C", "E", "G"]);
+    assert_eq!(parse("Ebm"), ["Eb", "Gb", "Bb"]);
+    assert_eq!(parse("Bdim"), ["B", "D", "F"]);
+    assert_eq!(parse("G#aug"), ["G#", "B#", "D##"]);
+    assert_eq!(parse("Dsus4"), ["D", "G", "A"]);
+    assert_eq!(parse("Fsus2"), ["F", "G", "C"]);
+}
+
+#[test]
+fn sevenths() {
+    assert_eq!(parse("G7"), ["G", "B", "D", "F"]);
+    assert_eq!(parse("Dbmaj7"), ["Db", "F", "Ab", "C"]);
+    assert_eq!(parse("Am7"), ["A", "C", "E", "G"]);
+    assert_eq!(parse("C#dim7"), ["C#", "E", "G", "Bb"]);
+    assert_eq!(parse("EmMaj7"), ["E", "G", "B", "D#"]);
+}
+
+#[test]
+fn aliases_mean_the_same_chord() {
+    for (symbol, alias) in [
+        ("Cm", "C-"),
+        ("Cm", "Cmin"),
+        ("Cmaj7", "CM7"),
+        ("Cm7b5", "Cø7"),
+        ("Caug", "C+"),
+    ] {
+        assert_eq!(parse(symbol), parse(alias));
+    }
+    assert_eq!("C-7".parse::<Chord>().unwrap().to_string(), "Cm7");
+}
+
+#[test]
+fn slash_chords() {
+    assert_eq!(parse("C/E"), ["E", "G", "C"]);
+    assert_eq!(parse("Bb7/Ab"), ["Ab", "Bb", "D", "F"]);
+    assert_eq!(parse("D/C"), ["C", "D", "F#", "A"]);
+    assert_eq!("Am7/G".parse::<Chord>().unwrap().to_string(), "Am7/G");
+}
+
+#[test]
+fn descriptive_errors() {
+    let error = |symbol: &str| symbol.parse::<Chord>().unwrap_err();
+    assert_eq!(error(""), r#""": a chord starts with its root"#);
+    assert_eq!(error("H7"), r#""H7": a chord starts with its root"#);
+    assert_eq!(error("C13"), r#""C13": "13" isn't a chord quality"#);
+    assert_eq!(error("C#b7"), r#""C#b7": "b7" isn't a chord quality"#);
+    assert_eq!(error("C/X"), r#""C/X": "X" isn't a bass note"#);
+}
+
+#[test]
+fn chords_from_scale_degrees() {
+    let scale = Scale::named("major", "D").unwrap();
+    let triads: Vec<String> = (1..=7)
+        .map(|d| scale.triad(d).unwrap().to_string())
+        .collect();
+    assert_eq!(triads, ["D", "Em", "F#m", "G", "A", "Bm", "C#dim"]);
+    assert_eq!(
+        names(&scale.seventh(5).unwrap().notes()),
+        ["A", "C#", "E", "G"]
+    );
+
+    let scale = Scale::named("melodic minor", "c").unwrap();
+    let sevenths: Vec<String> = (1..=7)
+        .map(|d| scale.seventh(d).unwrap().to_string())
+        .collect();
+    assert_eq!(
+        sevenths,
+        ["CmMaj7", "Dm7", "Ebmaj7#5", "F7", "G7", "Am7b5", "Bm7b5"]
+    );
+}
+
+#[test]
+fn chords_need_a_seven_note_scale() {
+    let scale = Scale::named("major", "C").unwrap();
+    assert!(scale.triad(0).is_err());
+    assert!(scale.triad(8).is_err());
+    assert!(Scale::named("blues", "C").unwrap().triad(1).is_err());
+}
+
+#[test]
+fn too_many_accidentals_arent_a_root() {
+    assert_eq!(
+        "C###7".parse::<Chord>().unwrap_err(),
+        r#""C###7": "C###" isn't a root"#
+    );
+    let symbol = format!("B{}7", "#".repeat(127));
+    assert!(symbol.parse::<Chord>().is_err());
+}
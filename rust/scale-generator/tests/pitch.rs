@@ -0,0 +1,135 @@
+use scale_generator::*;
+
+fn pitch(s: &str) -> Pitch {
+    s.parse().unwrap()
+}
+
+fn names(pitches: &[Pitch]) -> Vec<String> {
+    pitches.iter().map(Pitch::to_string).collect()
+}
+
+#[test]
+fn every_midi_number_round_trips() {
+    for n in 0..=127 {
+        let pitch = Pitch::from_midi(n).unwrap();
+        assert_eq!(pitch.midi(), Some(n));
+        assert_eq!(pitch.to_string().parse(), Ok(pitch));
+    }
+    assert_eq!(Pitch::from_midi(0), Ok(pitch("C-1")));
+    assert_eq!(Pitch::from_midi(127), Ok(pitch("G9")));
+    assert_eq!(
+        Pitch::from_midi(128),
+        Err("128: not a MIDI note number".to_string())
+    );
+}
+
+#[test]
+fn pitches_outside_midi() {
+    assert_eq!(pitch("B-2").midi(), None);
+    assert_eq!(pitch("G#9").midi(), None);
+}
+
+#[test]
+fn octaves_start_at_c() {
+    assert_eq!(pitch("B3").midi(), Some(59));
+    assert_eq!(pitch("C4").midi(), Some(60));
+    assert_eq!(pitch("Bb3").midi(), Some(58));
+    assert_eq!(pitch("B##3").midi(), pitch("C#4").midi());
+    assert_eq!(pitch("Cb4").midi(), pitch("B3").midi());
+
+    for s in ["B#3", "Cb4", "B##3", "Fbb-1"] {
+        assert_eq!(pitch(s).to_string(), s);
+    }
+}
+
+#[test]
+fn frequencies() {
+    let close = |pitch: &str, hz: f64| {
+        let pitch: Pitch = pitch.parse().unwrap();
+        let f = pitch.frequency(&Tuning::default());
+        assert!((f - hz).abs() < 0.01, "{}: {}", pitch, f);
+    };
+    close("A4", 440.0);
+    close("A5", 880.0);
+    close("C4", 261.63);
+    close("E2", 82.41);
+    close("C-1", 8.18);
+
+    assert_eq!(pitch("A4").frequency(&Tuning { a4: 432.0 }), 432.0);
+    assert_eq!(
+        pitch("Cb4").frequency(&Tuning::default()),
+        pitch("B3").frequency(&Tuning::default())
+    );
+}
+
+#[test]
+fn bad_pitches() {
+    for s in ["", "C", "4", "H4", "C#x4", "C4.5"] {
+        assert_eq!(s.parse::<Pitch>(), Err(format!("{:?}: not a pitch", s)));
+    }
+}
+
+#[test]
+fn scales_over_octaves() {
+    let scale = Scale::new("a", "MmMMmMM").unwrap();
+    let midi: Vec<u8> = scale
+        .pitches(2..=3)
+        .unwrap()
+        .iter()
+        .map(|p| p.midi().unwrap())
+        .collect();
+    assert_eq!(
+        midi,
+        [45, 47, 48, 50, 52, 53, 55, 57, 59, 60, 62, 64, 65, 67, 69]
+    );
+
+    let chromatic = Scale::chromatic("C").unwrap();
+    assert_eq!(chromatic.pitches(0..=8).unwrap().len(), 12 * 9 + 1);
+    let (first, last) = (4, 3);
+    assert!(chromatic.pitches(first..=last).unwrap().is_empty());
+}
+
+#[test]
+fn scales_over_octaves_keep_their_spelling() {
+    let scale = Scale::named("major", "F").unwrap();
+    assert_eq!(
+        names(&scale.pitches(4..=4).unwrap()),
+        ["F4", "G4", "A4", "Bb4", "C5", "D5", "E5", "F5"]
+    );
+
+    let scale = Scale::named("major", "Cb").unwrap();
+    let pitches = scale.pitches(4..=4).unwrap();
+    assert_eq!(
+        names(&pitches),
+        ["Cb4", "Db4", "Eb4", "Fb4", "Gb4", "Ab4", "Bb4", "Cb5"]
+    );
+    assert_eq!(pitches[0].midi(), Some(59));
+
+    let scale = Scale::named("major", "C#").unwrap();
+    let pitches = scale.pitches(3..=3).unwrap();
+    assert_eq!(names(&pitches)[6], "B#3");
+    assert_eq!(pitches[6].midi(), pitch("C4").midi());
+}
+
+#[test]
+fn too_many_accidentals_arent_a_pitch() {
+    for s in ["C###4".to_string(), format!("B{}4", "#".repeat(127))] {
+        assert_eq!(s.parse::<Pitch>(), Err(format!("{:?}: not a pitch", s)));
+    }
+}
+
+#[test]
+fn scales_at_the_top_of_the_octaves() {
+    let scale = Scale::named("major", "C").unwrap();
+    let pitches = scale.pitches(126..=126).unwrap();
+    assert_eq!(pitches.last().unwrap().to_string(), "C127");
+    assert_eq!(
+        scale.pitches(126..=127),
+        Err("126..=127: octaves out of range".to_string())
+    );
+
+    let scale = Scale::named("major", "B").unwrap();
+    assert!(scale.pitches(127..=127).is_err());
+    let scale = Scale::named("minor", "A").unwrap();
+    assert!(scale.pitches(-128..=-128).is_ok());
+}
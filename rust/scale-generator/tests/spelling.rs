@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use scale_generator::*;
+
+fn spelled(scale: &Scale) -> Vec<String> {
+    scale.spell().iter().map(SpelledNote::to_string).collect()
+}
+
+#[test]
+fn seven_note_scales_use_every_letter_once() {
+    let tonics = [
+        "C", "C#", "Db", "D", "Eb", "E", "F", "F#", "Gb", "G", "Ab", "A", "Bb", "B", "Cb", "E#",
+        "a", "bb", "d#", "g#", "fb",
+    ];
+    for named in CATALOGUE.iter().filter(|s| s.intervals.len() == 7) {
+        for tonic in tonics {
+            let scale = named.on(tonic).unwrap();
+            let notes = scale.spell();
+            let letters: HashSet<Letter> = notes.iter().map(|n| n.letter).collect();
+            assert_eq!(letters.len(), 7, "{} on {}", named.name, tonic);
+            assert_eq!(notes.first(), notes.last());
+
+            let sounding: Vec<Note> = notes.iter().map(SpelledNote::note).collect();
+            let enumerated: Vec<Note> = scale
+                .enumerate()
+                .iter()
+                .map(|n| n.parse().unwrap())
+                .collect();
+            assert_eq!(sounding, enumerated, "{} on {}", named.name, tonic);
+        }
+    }
+}
+
+#[test]
+fn flat_keys_get_flattened_letters() {
+    let scale = Scale::new("Gb", "MMmMMMm").unwrap();
+    assert_eq!(
+        spelled(&scale),
+        ["Gb", "Ab", "Bb", "Cb", "Db", "Eb", "F", "Gb"]
+    );
+    let scale = Scale::new("Cb", "MMmMMMm").unwrap();
+    assert_eq!(
+        spelled(&scale),
+        ["Cb", "Db", "Eb", "Fb", "Gb", "Ab", "Bb", "Cb"]
+    );
+}
+
+#[test]
+fn double_accidentals() {
+    let scale = Scale::named("harmonic minor", "g#").unwrap();
+    assert_eq!(
+        spelled(&scale),
+        ["G#", "A#", "B", "C#", "D#", "E", "F##", "G#"]
+    );
+    let scale = Scale::named("locrian", "Db").unwrap();
+    assert_eq!(
+        spelled(&scale),
+        ["Db", "Ebb", "Fb", "Gb", "Abb", "Bbb", "Cb", "Db"]
+    );
+}
+
+#[test]
+fn other_scales_keep_the_tonics_spelling() {
+    let scale = Scale::named("major pentatonic", "E#").unwrap();
+    assert_eq!(spelled(&scale), ["E#", "G", "A", "C", "D", "E#"]);
+}
+
+#[test]
+fn spelled_notes_round_trip() {
+    for s in ["C", "F#", "Bb", "E#", "Cb", "G##", "Dbb", "b", "abb"] {
+        let note: SpelledNote = s.parse().unwrap();
+        assert_eq!(note.to_string().to_lowercase(), s.to_lowercase());
+    }
+    for s in ["", "H", "C#b", "Cbx", "#"] {
+        assert_eq!(
+            s.parse::<SpelledNote>(),
+            Err(format!("{:?}: not a note", s))
+        );
+    }
+}
+
+#[test]
+fn long_runs_of_accidentals_arent_notes() {
+    let sharps = |n| format!("B{}", "#".repeat(n));
+    for s in [
+        "C###".to_string(),
+        "Dbbb".to_string(),
+        sharps(127),
+        sharps(128),
+    ] {
+        assert_eq!(
+            s.parse::<SpelledNote>(),
+            Err(format!("{:?}: not a note", s))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use scale_generator::*;
+
+const MODES: [Mode; 7] = [
+    Mode::Ionian,
+    Mode::Dorian,
+    Mode::Phrygian,
+    Mode::Lydian,
+    Mode::Mixolydian,
+    Mode::Aeolian,
+    Mode::Locrian,
+];
+
+#[test]
+fn every_tonic_resolves_in_every_mode() {
+    for letter in ["A", "B", "C", "D", "E", "F", "G"] {
+        for accidental in ["", "#", "b"] {
+            let upper = format!("{}{}", letter, accidental);
+            let lower = upper.to_lowercase();
+            for tonic in [&upper, &lower] {
+                assert!(tonic.parse::<Tonic>().is_ok(), "{}", tonic);
+                assert!(Scale::chromatic(tonic).is_ok(), "{}", tonic);
+                for mode in MODES {
+                    assert!(Tonic::in_mode(tonic, mode).is_ok(), "{} {:?}", tonic, mode);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn theoretical_keys_keep_their_accidentals() {
+    let sharps = |tonic: &str| Scale::chromatic(tonic).unwrap().enumerate()[1].clone();
+    assert_eq!(sharps("A#"), "B");
+    assert_eq!(sharps("C#"), "D");
+    assert_eq!(sharps("D#"), "E");
+    assert_eq!(sharps("G#"), "A");
+    assert_eq!(Scale::chromatic("G#").unwrap().enumerate()[2], "A#");
+
+    for tonic in ["db", "gb", "ab"] {
+        let notes = Scale::chromatic(tonic).unwrap().enumerate();
+        assert!(notes.iter().all(|n| !n.contains('#')), "{:?}", notes);
+    }
+}
+
+#[test]
+fn the_mode_decides_the_notation() {
+    let notation = |tonic, mode| Tonic::in_mode(tonic, mode).unwrap().notation;
+    assert_eq!(notation("E", Mode::Phrygian), TonicNotation::Sharp);
+    assert_eq!(notation("E", Mode::Locrian), TonicNotation::Flat);
+    assert_eq!(notation("F", Mode::Lydian), TonicNotation::Sharp);
+    assert_eq!(KeySignature::of("F", Mode::Lydian), Ok(KeySignature(0)));
+}
+
+#[test]
+fn tonics_that_arent_notes() {
+    for tonic in ["", "H", "C##", "Cx", "c-", "#"] {
+        assert_eq!(
+            tonic.parse::<Tonic>(),
+            Err(format!("{:?}: not a note", tonic))
+        );
+    }
+}
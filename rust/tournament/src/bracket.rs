@@ -0,0 +1,377 @@
+use std::{collections::HashSet, error::Error, fmt};
+
+use crate::{Fixture, Match, Outcome, Standings};
+
+/// One side of a `Tie`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Slot {
+    Team(String),
+    /// Nobody: the other side goes through.
+    Bye,
+    /// The winner of a tie that hasn't been decided yet.
+    Undecided,
+}
+
+impl fmt::Display for Slot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Slot::Team(team) => write!(f, "{}", team),
+            Slot::Bye => write!(f, "(bye)"),
+            Slot::Undecided => write!(f, "?"),
+        }
+    }
+}
+
+/// A knockout match, or a team's bye through a round.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Tie {
+    pub home: Slot,
+    pub away: Slot,
+    pub winner: Option<String>,
+}
+
+impl Tie {
+    fn has(&self, team: &str) -> bool {
+        [&self.home, &self.away].contains(&&Slot::Team(team.to_string()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum BracketError {
+    NotEnoughTeams,
+    DuplicateTeam(String),
+    /// A group in `Bracket::from_groups` with fewer teams than qualify.
+    SmallGroup(usize),
+    /// The team isn't in a tie that's ready to be played.
+    NotInPlay(String),
+    /// The teams aren't playing each other.
+    NotATie(String, String),
+    /// Someone has to win a knockout match.
+    Draw,
+}
+
+impl fmt::Display for BracketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BracketError::NotEnoughTeams => write!(f, "a bracket needs at least 2 teams"),
+            BracketError::DuplicateTeam(team) => write!(f, "{:?}: seeded twice", team),
+            BracketError::SmallGroup(g) => write!(f, "group {}: not enough teams to qualify", g),
+            BracketError::NotInPlay(team) => write!(f, "{:?}: not in a tie to be played", team),
+            BracketError::NotATie(home, away) => {
+                write!(f, "{:?} and {:?}: not playing each other", home, away)
+            }
+            BracketError::Draw => write!(f, "a knockout match can't be drawn"),
+        }
+    }
+}
+
+impl Error for BracketError {}
+
+/// The order seeds go in, top to bottom of a bracket of `size` (a power of
+/// two), so that the top seeds meet as late as possible: 1, 8, 4, 5, 2, 7, 3,
+/// 6 for 8.
+fn seed_order(size: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < size {
+        let n = order.len() * 2;
+        order = order.iter().flat_map(|s| [*s, n + 1 - s]).collect();
+    }
+    order
+}
+
+/// Orders the teams in `band`, each with its group, to be seeded after
+/// `seeded`: the first order there is that keeps teams from the same group
+/// apart in the first round, if there is one. `opponents` has each seed's
+/// first round opponent.
+///
+/// Teams in a band are all from different groups, so only opponents seeded
+/// before the band matter, and each seed can go to any team that isn't from
+/// its opponent's group.
+fn seed_apart<'a>(
+    seeded: &[(usize, &'a str)],
+    band: &[(usize, &'a str)],
+    opponents: &[usize],
+) -> Option<Vec<(usize, &'a str)>> {
+    let first = seeded.len() + 1;
+    let allowed = |seed: usize, (group, _): (usize, &str)| {
+        seeded
+            .get(opponents[seed] - 1)
+            .is_none_or(|(g, _)| *g != group)
+    };
+    let can_place = |seeds: std::ops::Range<usize>, teams: &[(usize, &str)]| {
+        let allowed: Vec<Vec<bool>> = seeds
+            .map(|seed| teams.iter().map(|t| allowed(seed, *t)).collect())
+            .collect();
+        can_assign(&allowed)
+    };
+    let last = first + band.len();
+    if !can_place(first..last, band) {
+        return None;
+    }
+
+    let mut rest = band.to_vec();
+    let mut ordered = vec![];
+    for seed in first..last {
+        let i = (0..rest.len())
+            .find(|i| {
+                let mut others = rest.clone();
+                let team = others.remove(*i);
+                allowed(seed, team) && can_place(seed + 1..last, &others)
+            })
+            .expect("the rest can be placed");
+        ordered.push(rest.remove(i));
+    }
+    Some(ordered)
+}
+
+/// Whether each row can have a column of its own that it's `allowed`, found
+/// with Kuhn's algorithm.
+fn can_assign(allowed: &[Vec<bool>]) -> bool {
+    fn claim(
+        row: usize,
+        allowed: &[Vec<bool>],
+        seen: &mut [bool],
+        owners: &mut [Option<usize>],
+    ) -> bool {
+        for column in 0..owners.len() {
+            if !allowed[row][column] || seen[column] {
+                continue;
+            }
+            seen[column] = true;
+            if owners[column].is_none_or(|owner| claim(owner, allowed, seen, owners)) {
+                owners[column] = Some(row);
+                return true;
+            }
+        }
+        false
+    }
+
+    let columns = allowed.first().map_or(0, Vec::len);
+    let mut owners = vec![None; columns];
+    (0..allowed.len()).all(|row| claim(row, allowed, &mut vec![false; columns], &mut owners))
+}
+
+/// A single-elimination tournament.
+///
+/// When the number of teams isn't a power of two, the top seeds get byes
+/// through the first round.
+///
+/// ```
+/// # use tournament::*;
+/// let mut bracket = Bracket::new(&["Alaskans", "Badgers", "Californians"]).unwrap();
+/// assert_eq!(
+///     bracket.to_string(),
+///     "\
+/// Semi-finals
+///   Alaskans v (bye): Alaskans
+///   Badgers v Californians
+/// Final
+///   Alaskans v ?",
+/// );
+///
+/// assert_eq!(bracket.fixtures(), vec![Fixture { home: "Badgers", away: "Californians" }]);
+/// bracket.record(&Match::try_from("Badgers;Californians;1-2").unwrap()).unwrap();
+/// assert_eq!(bracket.advance("Badgers"), Err(BracketError::NotInPlay("Badgers".to_string())));
+/// bracket.advance("Californians").unwrap();
+/// assert_eq!(bracket.champion(), Some("Californians"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Bracket {
+    rounds: Vec<Vec<Tie>>,
+}
+
+impl Bracket {
+    /// A bracket for `seeds`, the best first.
+    pub fn new(seeds: &[&str]) -> Result<Self, BracketError> {
+        if seeds.len() < 2 {
+            return Err(BracketError::NotEnoughTeams);
+        }
+        let mut seen = HashSet::new();
+        if let Some(team) = seeds.iter().find(|t| !seen.insert(**t)) {
+            return Err(BracketError::DuplicateTeam(team.to_string()));
+        }
+
+        let size = seeds.len().next_power_of_two();
+        let slot = |seed: usize| match seeds.get(seed - 1) {
+            Some(team) => Slot::Team(team.to_string()),
+            None => Slot::Bye,
+        };
+        let first = seed_order(size)
+            .chunks(2)
+            .map(|pair| Tie {
+                home: slot(pair[0]),
+                away: slot(pair[1]),
+                winner: None,
+            })
+            .collect();
+
+        let mut rounds = vec![first];
+        let mut ties = size / 4;
+        while ties > 0 {
+            rounds.push(vec![
+                Tie {
+                    home: Slot::Undecided,
+                    away: Slot::Undecided,
+                    winner: None,
+                };
+                ties
+            ]);
+            ties /= 2;
+        }
+
+        let mut bracket = Bracket { rounds };
+        for i in 0..bracket.rounds[0].len() {
+            if let (Slot::Team(team), Slot::Bye) | (Slot::Bye, Slot::Team(team)) =
+                (&bracket.rounds[0][i].home, &bracket.rounds[0][i].away)
+            {
+                bracket.set_winner(0, i, team.clone());
+            }
+        }
+        Ok(bracket)
+    }
+
+    /// A bracket for the top `qualifiers` of each group: first every group
+    /// winner, then every runner up, and so on. Within each of those, teams
+    /// are seeded in the order of their groups, except where that would
+    /// have two teams from the same group meet in the first round, which is
+    /// avoided where it can be.
+    ///
+    /// ```
+    /// # use tournament::*;
+    /// let a: Standings = "Alaskans;Badgers;win\nBadgers;Californians;win".parse().unwrap();
+    /// let b: Standings = "Donkeys;Elephants;loss\nFlamingos;Donkeys;loss".parse().unwrap();
+    ///
+    /// let bracket = Bracket::from_groups(&[a, b], 2).unwrap();
+    /// assert_eq!(
+    ///     bracket.fixtures(),
+    ///     vec![
+    ///         Fixture { home: "Alaskans", away: "Elephants" },
+    ///         Fixture { home: "Donkeys", away: "Badgers" },
+    ///     ],
+    /// );
+    /// ```
+    pub fn from_groups(groups: &[Standings], qualifiers: usize) -> Result<Self, BracketError> {
+        if groups.len() * qualifiers < 2 {
+            return Err(BracketError::NotEnoughTeams);
+        }
+        let size = (groups.len() * qualifiers).next_power_of_two();
+        let mut opponents = vec![0; size + 1];
+        for pair in seed_order(size).chunks(2) {
+            opponents[pair[0]] = pair[1];
+            opponents[pair[1]] = pair[0];
+        }
+
+        let mut seeds = vec![];
+        for place in 0..qualifiers {
+            let mut band = vec![];
+            for (g, group) in groups.iter().enumerate() {
+                let record = group
+                    .records()
+                    .get(place)
+                    .ok_or(BracketError::SmallGroup(g))?;
+                band.push((g, record.team.as_str()));
+            }
+            let band = seed_apart(&seeds, &band, &opponents).unwrap_or(band);
+            seeds.extend(band);
+        }
+
+        let seeds: Vec<&str> = seeds.into_iter().map(|(_, team)| team).collect();
+        Bracket::new(&seeds)
+    }
+
+    /// The first round first.
+    pub fn rounds(&self) -> &[Vec<Tie>] {
+        &self.rounds
+    }
+
+    pub fn champion(&self) -> Option<&str> {
+        self.rounds.last()?[0].winner.as_deref()
+    }
+
+    /// The ties that are ready to be played.
+    pub fn fixtures(&self) -> Vec<Fixture<'_>> {
+        self.rounds
+            .iter()
+            .flatten()
+            .filter(|t| t.winner.is_none())
+            .filter_map(|t| match (&t.home, &t.away) {
+                (Slot::Team(home), Slot::Team(away)) => Some(Fixture { home, away }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn set_winner(&mut self, round: usize, i: usize, winner: String) {
+        self.rounds[round][i].winner = Some(winner.clone());
+        if let Some(next) = self.rounds.get_mut(round + 1) {
+            let tie = &mut next[i / 2];
+            if i.is_multiple_of(2) {
+                tie.home = Slot::Team(winner);
+            } else {
+                tie.away = Slot::Team(winner);
+            }
+        }
+    }
+
+    /// Puts `winner` through to the next round.
+    pub fn advance(&mut self, winner: &str) -> Result<(), BracketError> {
+        let (round, i) = self
+            .find(|t| t.has(winner))
+            .ok_or_else(|| BracketError::NotInPlay(winner.to_string()))?;
+        self.set_winner(round, i, winner.to_string());
+        Ok(())
+    }
+
+    /// Puts the winner of `m` through to the next round.
+    pub fn record(&mut self, m: &Match) -> Result<(), BracketError> {
+        let winner = match m.outcome {
+            Outcome::Win => m.home,
+            Outcome::Loss => m.away,
+            Outcome::Draw => return Err(BracketError::Draw),
+        };
+        let (round, i) = self
+            .find(|t| t.has(m.home) && t.has(m.away))
+            .ok_or_else(|| BracketError::NotATie(m.home.to_string(), m.away.to_string()))?;
+        self.set_winner(round, i, winner.to_string());
+        Ok(())
+    }
+
+    /// The round and index of the first tie ready to be played that matches.
+    fn find(&self, matches: impl Fn(&Tie) -> bool) -> Option<(usize, usize)> {
+        self.rounds.iter().enumerate().find_map(|(round, ties)| {
+            ties.iter()
+                .position(|t| {
+                    t.winner.is_none()
+                        && matches!((&t.home, &t.away), (Slot::Team(_), Slot::Team(_)))
+                        && matches(t)
+                })
+                .map(|i| (round, i))
+        })
+    }
+}
+
+fn round_name(ties: usize) -> String {
+    match ties {
+        1 => "Final".to_string(),
+        2 => "Semi-finals".to_string(),
+        4 => "Quarter-finals".to_string(),
+        _ => format!("Round of {}", ties * 2),
+    }
+}
+
+impl fmt::Display for Bracket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut lines = vec![];
+        for ties in self.rounds.iter() {
+            lines.push(round_name(ties.len()));
+            for t in ties {
+                let mut line = format!("  {} v {}", t.home, t.away);
+                if let Some(winner) = &t.winner {
+                    line += &format!(": {}", winner);
+                }
+                lines.push(line);
+            }
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
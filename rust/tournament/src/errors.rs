@@ -0,0 +1,149 @@
+use std::{error::Error, fmt};
+
+use crate::Match;
+
+/// Why a line isn't a `Match`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum MatchError {
+    /// There should be 3 fields separated by `;`.
+    FieldCount(usize),
+    /// The third field is neither an `Outcome` nor a `Score`.
+    UnknownOutcome(String),
+    PlaysItself(String),
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchError::FieldCount(n) => {
+                write!(f, "{} fields: must have 3 fields separated by ';'", n)
+            }
+            MatchError::UnknownOutcome(s) => {
+                write!(f, "{:?}: not a valid Outcome or Score", s)
+            }
+            MatchError::PlaysItself(team) => write!(f, "{:?}: can't play itself", team),
+        }
+    }
+}
+
+impl Error for MatchError {}
+
+/// A match that `Standings::retract` can't take back, since it wasn't
+/// counted.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct NotRecorded {
+    pub home: String,
+    pub away: String,
+}
+
+impl fmt::Display for NotRecorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} v {:?}: no such match counted",
+            self.home, self.away
+        )
+    }
+}
+
+impl Error for NotRecorded {}
+
+/// A line of input that isn't a `Match`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct LineError {
+    /// Counting from 1.
+    pub line: usize,
+    pub text: String,
+    pub error: MatchError,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {:?}: {}", self.line, self.text, self.error)
+    }
+}
+
+impl Error for LineError {}
+
+/// Every line of some input that isn't a `Match`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InvalidLines(pub Vec<LineError>);
+
+impl fmt::Display for InvalidLines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self.0.iter().map(LineError::to_string).collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl Error for InvalidLines {}
+
+/// The lines of some input that are `Match`es, and the ones that aren't.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ParsedMatches<'a> {
+    pub matches: Vec<Match<'a>>,
+    pub errors: Vec<LineError>,
+}
+
+impl<'a> ParsedMatches<'a> {
+    /// The matches, if every line was one.
+    pub fn strict(self) -> Result<Vec<Match<'a>>, InvalidLines> {
+        if self.errors.is_empty() {
+            Ok(self.matches)
+        } else {
+            Err(InvalidLines(self.errors))
+        }
+    }
+}
+
+/// Parses every non-empty line of `input`, keeping going past the ones that
+/// aren't `Match`es.
+///
+/// ```
+/// # use tournament::*;
+/// let parsed = parse_matches("\
+/// Allegoric Alaskans;Blithering Badgers;win
+/// Allegoric Alaskans;Blithering Badgers
+///
+/// Blithering Badgers;Blithering Badgers;draw
+/// Blithering Badgers;Allegoric Alaskans;lost");
+///
+/// assert_eq!(parsed.matches.len(), 1);
+/// let errors: Vec<(usize, MatchError)> =
+///     parsed.errors.iter().map(|e| (e.line, e.error.clone())).collect();
+/// assert_eq!(
+///     errors,
+///     vec![
+///         (2, MatchError::FieldCount(2)),
+///         (4, MatchError::PlaysItself("Blithering Badgers".to_string())),
+///         (5, MatchError::UnknownOutcome("lost".to_string())),
+///     ],
+/// );
+/// assert_eq!(
+///     parsed.errors[0].to_string(),
+///     r#"line 2: "Allegoric Alaskans;Blithering Badgers": 2 fields: must have 3 fields separated by ';'"#,
+/// );
+///
+/// // Tally the matches that are there,
+/// let standings = Standings::from_matches(parsed.matches.clone());
+/// assert_eq!(standings.records()[0].points, 3);
+/// // or give up.
+/// assert!(parsed.strict().is_err());
+/// ```
+pub fn parse_matches(input: &str) -> ParsedMatches<'_> {
+    let mut parsed = ParsedMatches::default();
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        match Match::try_from(line) {
+            Ok(m) => parsed.matches.push(m),
+            Err(error) => parsed.errors.push(LineError {
+                line: i + 1,
+                text: line.to_string(),
+                error,
+            }),
+        }
+    }
+    parsed
+}
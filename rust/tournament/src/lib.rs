@@ -1,7 +1,25 @@
-use std::{collections::HashMap, fmt::Display, str::FromStr};
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Outcome {
+mod bracket;
+mod errors;
+mod rating;
+mod render;
+mod rules;
+mod schedule;
+mod standings;
+mod stream;
+
+pub use bracket::*;
+pub use errors::*;
+pub use rating::*;
+pub use render::*;
+pub use rules::*;
+pub use schedule::*;
+pub use standings::*;
+pub use stream::*;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Outcome {
     Loss,
     Draw,
     Win,
@@ -9,20 +27,14 @@ enum Outcome {
 use Outcome::*;
 
 impl Outcome {
-    fn flip(&self) -> Self {
+    /// The outcome for the other team.
+    pub fn flip(&self) -> Self {
         match self {
             Loss => Win,
             Draw => Draw,
             Win => Loss,
         }
     }
-    fn points(&self) -> i32 {
-        match self {
-            Loss => 0,
-            Draw => 1,
-            Win => 3,
-        }
-    }
 }
 
 impl FromStr for Outcome {
@@ -38,79 +50,96 @@ impl FromStr for Outcome {
     }
 }
 
-#[derive(Debug)]
-struct Match<'a> {
-    home: &'a str,
-    away: &'a str,
-    outcome: Outcome,
+/// The goals each team scored, written like `3-1`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Score {
+    pub home: u32,
+    pub away: u32,
 }
 
-impl<'a> Match<'a> {
-    fn from_str<'b: 'a>(s: &'b str) -> Result<Self, String> {
-        let atoms: Vec<&'b str> = s.splitn(3, ';').collect();
-        if atoms.len() != 3 {
-            return Err(format!("{:?} must have 3 fields separated by ';'.", s));
-        };
-        Ok(Match::<'a> {
-            home: atoms[0],
-            away: atoms[1],
-            outcome: atoms[2].parse()?,
-        })
+impl Score {
+    /// The outcome for the home team.
+    pub fn outcome(&self) -> Outcome {
+        match self.home.cmp(&self.away) {
+            std::cmp::Ordering::Less => Loss,
+            std::cmp::Ordering::Equal => Draw,
+            std::cmp::Ordering::Greater => Win,
+        }
     }
 }
 
-struct LeaderboardEntry<'a> {
-    team: &'a str,
-    mp: i32,
-    w: i32,
-    d: i32,
-    l: i32,
-    p: i32,
-}
+impl FromStr for Score {
+    type Err = String;
 
-impl<'a> Display for LeaderboardEntry<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{:<30} | {:>2} | {:>2} | {:>2} | {:>2} | {:>2}",
-            self.team, self.mp, self.w, self.l, self.d, self.p,
-        )
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let goals = |g: &str| g.parse::<u32>().ok();
+        match s.split_once('-') {
+            Some((home, away)) => match (goals(home), goals(away)) {
+                (Some(home), Some(away)) => Ok(Score { home, away }),
+                _ => Err(format!("{:?}: not a valid Score", s)),
+            },
+            None => Err(format!("{:?}: not a valid Score", s)),
+        }
     }
 }
 
-const HEADER: &str = "Team                           | MP |  W |  D |  L |  P";
+/// A match result, with the outcome for the home team, and the score if it
+/// was given rather than just the outcome.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Match<'a> {
+    pub home: &'a str,
+    pub away: &'a str,
+    pub outcome: Outcome,
+    pub score: Option<Score>,
+}
 
-pub fn tally(match_results: &str) -> String {
-    let matches: Vec<Match> = match_results
-        .split('\n')
-        .filter(|s| !s.is_empty())
-        .map(|l| Match::from_str(l).unwrap())
-        .collect();
-
-    let team_outcomes: HashMap<&str, Vec<Outcome>> = matches
-        .iter()
-        .flat_map(|m| [(m.home, m.outcome), (m.away, m.outcome.flip())])
-        .fold(HashMap::<&str, Vec<Outcome>>::new(), |mut ti, (t, m)| {
-            ti.entry(t).or_default().push(m);
-            ti
-        });
-
-    let mut leaderboard_entries = team_outcomes
-        .iter()
-        .map(|(team, outcomes)| LeaderboardEntry {
-            team: *team,
-            mp: outcomes.len() as i32,
-            w: outcomes.iter().filter(|o| **o == Win).count() as i32,
-            d: outcomes.iter().filter(|o| **o == Loss).count() as i32,
-            l: outcomes.iter().filter(|o| **o == Draw).count() as i32,
-            p: outcomes.iter().map(|o| o.points()).sum(),
+/// ```
+/// # use tournament::*;
+/// let m = Match::try_from("Courageous Californians;Devastating Donkeys;loss").unwrap();
+/// assert_eq!(m.home, "Courageous Californians");
+/// assert_eq!(m.outcome, Outcome::Loss);
+/// assert_eq!(m.score, None);
+/// assert!(Match::try_from("Courageous Californians;Devastating Donkeys").is_err());
+///
+/// let m = Match::try_from("Courageous Californians;Devastating Donkeys;3-1").unwrap();
+/// assert_eq!(m.outcome, Outcome::Win);
+/// assert_eq!(m.score, Some(Score { home: 3, away: 1 }));
+/// assert!(Match::try_from("Courageous Californians;Devastating Donkeys;3-").is_err());
+/// ```
+impl<'a> TryFrom<&'a str> for Match<'a> {
+    type Error = MatchError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let atoms: Vec<&'a str> = s.split(';').collect();
+        let [home, away, result] = atoms[..] else {
+            return Err(MatchError::FieldCount(atoms.len()));
+        };
+        if home == away {
+            return Err(MatchError::PlaysItself(home.to_string()));
+        }
+
+        let unknown = |_| MatchError::UnknownOutcome(result.to_string());
+        let (outcome, score) = if result.contains('-') {
+            let score: Score = result.parse().map_err(unknown)?;
+            (score.outcome(), Some(score))
+        } else {
+            (result.parse().map_err(unknown)?, None)
+        };
+        Ok(Match {
+            home,
+            away,
+            outcome,
+            score,
         })
-        .collect::<Vec<LeaderboardEntry>>();
-    leaderboard_entries.sort_by_key(|le| (-le.p, le.team));
-
-    vec![HEADER.to_string()]
-        .into_iter()
-        .chain(leaderboard_entries.iter().map(LeaderboardEntry::to_string))
-        .collect::<Vec<String>>()
-        .join("\n")
+    }
+}
+
+pub fn tally(match_results: &str) -> String {
+    tally_with(match_results, &LeagueRules::default())
+}
+
+pub fn tally_with(match_results: &str, rules: &LeagueRules) -> String {
+    Standings::parse_with(match_results, rules)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .to_string()
 }
@@ -0,0 +1,278 @@
+use std::{collections::HashMap, f64::consts::PI};
+
+use crate::{Match, Outcome};
+
+/// A way to rate teams' strength from their results.
+pub trait RatingSystem {
+    type Rating: Clone;
+
+    /// A team's rating before its first match.
+    fn initial(&self) -> Self::Rating;
+
+    /// What teams are ranked by: the higher the stronger.
+    fn strength(&self, rating: &Self::Rating) -> f64;
+
+    /// The home and away teams' ratings after a match that ended in
+    /// `outcome` for the home team.
+    fn rate(
+        &self,
+        home: &Self::Rating,
+        away: &Self::Rating,
+        outcome: Outcome,
+    ) -> (Self::Rating, Self::Rating);
+}
+
+/// The share of the points from a match: 1 for a win, ½ for a draw.
+fn score(outcome: Outcome) -> f64 {
+    match outcome {
+        Outcome::Win => 1.0,
+        Outcome::Draw => 0.5,
+        Outcome::Loss => 0.0,
+    }
+}
+
+/// The chance of beating an opponent rated `difference` points lower, with
+/// the difference scaled by `g` as Glicko does.
+fn expected(difference: f64, g: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-g * difference / 400.0))
+}
+
+/// Elo ratings: the winner takes points off the loser, more the less
+/// expected the win was.
+///
+/// ```
+/// # use tournament::*;
+/// let elo = Elo::default();
+/// assert_eq!(elo.rate(&1500.0, &1500.0, Outcome::Win), (1510.0, 1490.0));
+/// assert_eq!(elo.rate(&1500.0, &1500.0, Outcome::Draw), (1500.0, 1500.0));
+///
+/// let elo = Elo { home_advantage: 100.0, ..Elo::default() };
+/// let (home, away) = elo.rate(&1500.0, &1500.0, Outcome::Draw);
+/// assert!(home < 1500.0 && away > 1500.0);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Elo {
+    pub initial: f64,
+    /// The most points a match can be worth.
+    pub k: f64,
+    /// Points added to the home team's rating when working out who's
+    /// expected to win.
+    pub home_advantage: f64,
+}
+
+impl Default for Elo {
+    fn default() -> Self {
+        Elo {
+            initial: 1500.0,
+            k: 20.0,
+            home_advantage: 0.0,
+        }
+    }
+}
+
+impl RatingSystem for Elo {
+    type Rating = f64;
+
+    fn initial(&self) -> f64 {
+        self.initial
+    }
+
+    fn strength(&self, rating: &f64) -> f64 {
+        *rating
+    }
+
+    fn rate(&self, home: &f64, away: &f64, outcome: Outcome) -> (f64, f64) {
+        let e = expected(home + self.home_advantage - away, 1.0);
+        let change = self.k * (score(outcome) - e);
+        (home + change, away - change)
+    }
+}
+
+/// A Glicko rating: how strong a team is, and how sure we are of it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GlickoRating {
+    pub rating: f64,
+    /// The rating's standard deviation.
+    pub deviation: f64,
+}
+
+/// Glicko ratings, with every match as its own rating period. Results move
+/// the ratings of teams we're unsure of more, and against teams we're unsure
+/// of less.
+///
+/// ```
+/// # use tournament::*;
+/// let glicko = Glicko::default();
+/// let new = glicko.initial();
+/// let (home, away) = glicko.rate(&new, &new, Outcome::Win);
+/// assert!(home.rating > 1500.0 && away.rating < 1500.0);
+/// assert!(home.deviation < new.deviation);
+///
+/// let known = GlickoRating { rating: 1500.0, deviation: 50.0 };
+/// let (upset, _) = glicko.rate(&known, &new, Outcome::Loss);
+/// assert!(1500.0 - upset.rating < 1500.0 - away.rating);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Glicko {
+    pub initial: GlickoRating,
+    /// How much less sure we get of a team's rating between matches.
+    pub deviation_growth: f64,
+    pub home_advantage: f64,
+}
+
+impl Default for Glicko {
+    fn default() -> Self {
+        Glicko {
+            initial: GlickoRating {
+                rating: 1500.0,
+                deviation: 350.0,
+            },
+            deviation_growth: 0.0,
+            home_advantage: 0.0,
+        }
+    }
+}
+
+impl Glicko {
+    fn update(
+        &self,
+        us: &GlickoRating,
+        them: &GlickoRating,
+        difference: f64,
+        s: f64,
+    ) -> GlickoRating {
+        let q = 10f64.ln() / 400.0;
+        let g = 1.0 / (1.0 + 3.0 * (q * them.deviation / PI).powi(2)).sqrt();
+        let e = expected(difference, g);
+        let d2 = 1.0 / (q * q * g * g * e * (1.0 - e));
+
+        let deviation = (us.deviation.powi(2) + self.deviation_growth.powi(2))
+            .sqrt()
+            .min(self.initial.deviation);
+        let precision = 1.0 / deviation.powi(2) + 1.0 / d2;
+        GlickoRating {
+            rating: us.rating + q / precision * g * (s - e),
+            deviation: (1.0 / precision).sqrt(),
+        }
+    }
+}
+
+impl RatingSystem for Glicko {
+    type Rating = GlickoRating;
+
+    fn initial(&self) -> GlickoRating {
+        self.initial
+    }
+
+    fn strength(&self, rating: &GlickoRating) -> f64 {
+        rating.rating
+    }
+
+    fn rate(
+        &self,
+        home: &GlickoRating,
+        away: &GlickoRating,
+        outcome: Outcome,
+    ) -> (GlickoRating, GlickoRating) {
+        let difference = home.rating + self.home_advantage - away.rating;
+        (
+            self.update(home, away, difference, score(outcome)),
+            self.update(away, home, -difference, score(outcome.flip())),
+        )
+    }
+}
+
+/// Every team's rating after every match, in the order they're recorded.
+///
+/// ```
+/// # use tournament::*;
+/// let matches = parse_matches("\
+/// Allegoric Alaskans;Blithering Badgers;win
+/// Blithering Badgers;Courageous Californians;draw")
+///     .strict()
+///     .unwrap();
+/// let ratings = Ratings::from_matches(Elo::default(), matches);
+///
+/// assert_eq!(ratings.rating("Allegoric Alaskans"), Some(&1510.0));
+/// assert_eq!(ratings.history("Blithering Badgers").unwrap().len(), 3);
+/// assert_eq!(ratings.rating("Devastating Donkeys"), None);
+/// assert_eq!(ratings.ranked()[0], ("Allegoric Alaskans", &1510.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ratings<S: RatingSystem> {
+    system: S,
+    histories: HashMap<String, Vec<S::Rating>>,
+}
+
+impl<S: RatingSystem> Ratings<S> {
+    pub fn new(system: S) -> Self {
+        Ratings {
+            system,
+            histories: HashMap::new(),
+        }
+    }
+
+    pub fn from_matches<'a>(system: S, matches: impl IntoIterator<Item = Match<'a>>) -> Self {
+        let mut ratings = Ratings::new(system);
+        for m in matches {
+            ratings.record(&m);
+        }
+        ratings
+    }
+
+    pub fn system(&self) -> &S {
+        &self.system
+    }
+
+    /// Rates the teams in `m`, which comes after every match so far.
+    pub fn record(&mut self, m: &Match) {
+        let (home, away) =
+            self.system
+                .rate(&self.current(m.home), &self.current(m.away), m.outcome);
+        for (team, rating) in [(m.home, home), (m.away, away)] {
+            match self.histories.get_mut(team) {
+                Some(history) => history.push(rating),
+                None => {
+                    let history = vec![self.system.initial(), rating];
+                    self.histories.insert(team.to_string(), history);
+                }
+            }
+        }
+    }
+
+    fn current(&self, team: &str) -> S::Rating {
+        self.rating(team)
+            .cloned()
+            .unwrap_or_else(|| self.system.initial())
+    }
+
+    /// The team's rating now, if it's played.
+    pub fn rating(&self, team: &str) -> Option<&S::Rating> {
+        self.histories.get(team)?.last()
+    }
+
+    /// The team's initial rating, and its rating after each of its matches.
+    pub fn history(&self, team: &str) -> Option<&[S::Rating]> {
+        self.histories.get(team).map(Vec::as_slice)
+    }
+
+    pub fn teams(&self) -> impl Iterator<Item = &str> {
+        self.histories.keys().map(String::as_str)
+    }
+
+    /// Every team, the strongest first.
+    pub fn ranked(&self) -> Vec<(&str, &S::Rating)> {
+        let mut ranked: Vec<(&str, &S::Rating)> = self
+            .histories
+            .iter()
+            .map(|(t, h)| (t.as_str(), h.last().expect("every history has a rating")))
+            .collect();
+        ranked.sort_by(|a, b| {
+            let strength = |r| self.system.strength(r);
+            strength(b.1)
+                .total_cmp(&strength(a.1))
+                .then_with(|| a.0.cmp(b.0))
+        });
+        ranked
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Standings, TeamRecord};
+
+/// A way to write out `Standings`.
+pub trait Renderer {
+    fn render(&self, standings: &Standings) -> String;
+}
+
+/// A column's name, and how to get its value from a `TeamRecord`.
+type Column = (&'static str, fn(&TeamRecord) -> u32);
+
+/// The columns of the human readable leaderboards.
+const COLUMNS: [Column; 5] = [
+    ("MP", |r| r.played),
+    ("W", |r| r.won),
+    ("D", |r| r.drawn),
+    ("L", |r| r.lost),
+    ("P", |r| r.points),
+];
+
+/// A plain text table, as `tally` writes it. Columns are as wide as their
+/// widest entry, and team names get at least 30 characters.
+///
+/// ```
+/// # use tournament::*;
+/// let standings: Standings = "\
+/// Allegoric Alaskans;Blithering Badgers;win
+/// Blithering Badgers;The Extraordinarily Long-Named Llamas;draw"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(
+///     standings.render(&Text),
+///     "\
+/// Team                                  | MP |  W |  D |  L |  P
+/// Allegoric Alaskans                    |  1 |  1 |  0 |  0 |  3
+/// Blithering Badgers                    |  2 |  0 |  1 |  1 |  1
+/// The Extraordinarily Long-Named Llamas |  1 |  0 |  1 |  0 |  1",
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Text;
+
+impl Renderer for Text {
+    fn render(&self, standings: &Standings) -> String {
+        let records = standings.records();
+        let team_width = records
+            .iter()
+            .map(|r| r.team.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(30);
+        let widths: Vec<usize> = COLUMNS
+            .iter()
+            .map(|(_, field)| {
+                records
+                    .iter()
+                    .map(|r| field(r).to_string().len())
+                    .max()
+                    .unwrap_or(0)
+                    .max(2)
+            })
+            .collect();
+
+        let row = |team: &str, cells: Vec<String>| {
+            let mut row = format!("{:<width$}", team, width = team_width);
+            for (cell, width) in cells.iter().zip(&widths) {
+                row += &format!(" | {:>width$}", cell, width = width);
+            }
+            row
+        };
+
+        let mut lines = vec![row(
+            "Team",
+            COLUMNS.iter().map(|(name, _)| name.to_string()).collect(),
+        )];
+        for r in records {
+            lines.push(row(
+                &r.team,
+                COLUMNS
+                    .iter()
+                    .map(|(_, field)| field(r).to_string())
+                    .collect(),
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Every field of every `TeamRecord`, with a header line.
+///
+/// ```
+/// # use tournament::*;
+/// let standings: Standings = "Allegoric Alaskans;\"Badgers, Blithering\";2-1".parse().unwrap();
+/// assert_eq!(
+///     standings.render(&Csv),
+///     "\
+/// team,played,won,drawn,lost,points,goals_for,goals_against
+/// Allegoric Alaskans,1,1,0,0,3,2,1
+/// \"\"\"Badgers, Blithering\"\"\",1,0,0,1,0,1,2
+/// ",
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Csv;
+
+impl Renderer for Csv {
+    fn render(&self, standings: &Standings) -> String {
+        let mut csv = "team,played,won,drawn,lost,points,goals_for,goals_against\n".to_string();
+        for r in standings.records() {
+            let team = if r.team.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", r.team.replace('"', "\"\""))
+            } else {
+                r.team.clone()
+            };
+            csv += &format!(
+                "{},{},{},{},{},{},{},{}\n",
+                team, r.played, r.won, r.drawn, r.lost, r.points, r.goals_for, r.goals_against
+            );
+        }
+        csv
+    }
+}
+
+/// An array of objects with every field of every `TeamRecord`.
+///
+/// ```
+/// # use tournament::*;
+/// let standings: Standings = "Allegoric Alaskans;Blithering \"B\" Badgers;win".parse().unwrap();
+/// assert_eq!(
+///     standings.render(&Json),
+///     concat!(
+///         r#"[{"team":"Allegoric Alaskans","played":1,"won":1,"drawn":0,"lost":0,"points":3,"goals_for":0,"goals_against":0},"#,
+///         r#"{"team":"Blithering \"B\" Badgers","played":1,"won":0,"drawn":0,"lost":1,"points":0,"goals_for":0,"goals_against":0}]"#,
+///     ),
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Json;
+
+fn json_string(s: &str) -> String {
+    let mut json = "\"".to_string();
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+impl Renderer for Json {
+    fn render(&self, standings: &Standings) -> String {
+        let objects: Vec<String> = standings
+            .records()
+            .iter()
+            .map(|r| {
+                format!(
+                    concat!(
+                        r#"{{"team":{},"played":{},"won":{},"drawn":{},"lost":{},"#,
+                        r#""points":{},"goals_for":{},"goals_against":{}}}"#,
+                    ),
+                    json_string(&r.team),
+                    r.played,
+                    r.won,
+                    r.drawn,
+                    r.lost,
+                    r.points,
+                    r.goals_for,
+                    r.goals_against
+                )
+            })
+            .collect();
+        format!("[{}]", objects.join(","))
+    }
+}
+
+/// A GitHub flavoured Markdown table.
+///
+/// ```
+/// # use tournament::*;
+/// let standings: Standings = "Allegoric Alaskans;Blithering|Badgers;win".parse().unwrap();
+/// assert_eq!(
+///     standings.render(&Markdown),
+///     "\
+/// | Team | MP | W | D | L | P |
+/// | :--- | -: | -: | -: | -: | -: |
+/// | Allegoric Alaskans | 1 | 1 | 0 | 0 | 3 |
+/// | Blithering\\|Badgers | 1 | 0 | 0 | 1 | 0 |
+/// ",
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Markdown;
+
+impl Renderer for Markdown {
+    fn render(&self, standings: &Standings) -> String {
+        let names: Vec<&str> = COLUMNS.iter().map(|(name, _)| *name).collect();
+        let mut md = format!("| Team | {} |\n", names.join(" | "));
+        md += &format!("| :--- |{}\n", " -: |".repeat(COLUMNS.len()));
+        for r in standings.records() {
+            let cells: Vec<String> = COLUMNS.iter().map(|(_, f)| f(r).to_string()).collect();
+            md += &format!(
+                "| {} | {} |\n",
+                r.team.replace('|', "\\|"),
+                cells.join(" | ")
+            );
+        }
+        md
+    }
+}
+
+/// An HTML `<table>`.
+///
+/// ```
+/// # use tournament::*;
+/// let standings: Standings = "Allegoric Alaskans;Badgers & <Co>;win".parse().unwrap();
+/// let html = standings.render(&Html);
+/// assert!(html.starts_with("<table>\n<thead>\n<tr><th>Team</th><th>MP</th>"));
+/// assert!(html.contains("<tr><td>Badgers &amp; &lt;Co&gt;</td><td>1</td><td>0</td>"));
+/// assert!(html.ends_with("</tbody>\n</table>\n"));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Html;
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+impl Renderer for Html {
+    fn render(&self, standings: &Standings) -> String {
+        let mut html = "<table>\n<thead>\n<tr><th>Team</th>".to_string();
+        for (name, _) in COLUMNS {
+            html += &format!("<th>{}</th>", name);
+        }
+        html += "</tr>\n</thead>\n<tbody>\n";
+        for r in standings.records() {
+            html += &format!("<tr><td>{}</td>", html_escape(&r.team));
+            for (_, field) in COLUMNS {
+                html += &format!("<td>{}</td>", field(r));
+            }
+            html += "</tr>\n";
+        }
+        html + "</tbody>\n</table>\n"
+    }
+}
@@ -0,0 +1,116 @@
+use crate::Outcome;
+
+/// How many points a team gets for each outcome.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct PointsTable {
+    pub win: u32,
+    pub draw: u32,
+    pub loss: u32,
+}
+
+impl PointsTable {
+    pub const THREE_FOR_A_WIN: PointsTable = PointsTable {
+        win: 3,
+        draw: 1,
+        loss: 0,
+    };
+    pub const TWO_FOR_A_WIN: PointsTable = PointsTable {
+        win: 2,
+        draw: 1,
+        loss: 0,
+    };
+
+    pub fn points(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Win => self.win,
+            Outcome::Draw => self.draw,
+            Outcome::Loss => self.loss,
+        }
+    }
+}
+
+impl Default for PointsTable {
+    fn default() -> Self {
+        PointsTable::THREE_FOR_A_WIN
+    }
+}
+
+/// Extra points, on top of the `PointsTable`'s.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Bonus {
+    /// For winning away from home.
+    AwayWin(u32),
+    /// For drawing away from home.
+    AwayDraw(u32),
+}
+
+impl Bonus {
+    /// The bonus for a team that played at home, or not, to `outcome`.
+    pub fn points(&self, home: bool, outcome: Outcome) -> u32 {
+        match (self, home, outcome) {
+            (Bonus::AwayWin(p), false, Outcome::Win)
+            | (Bonus::AwayDraw(p), false, Outcome::Draw) => *p,
+            _ => 0,
+        }
+    }
+}
+
+/// A way to order teams that have the same points.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Tiebreak {
+    /// Most points in the matches between the tied teams.
+    HeadToHead,
+    /// Most wins.
+    Wins,
+    /// Most goals scored, less goals conceded.
+    GoalDifference,
+    /// Most goals scored.
+    GoalsFor,
+    /// Alphabetically.
+    Name,
+}
+
+/// How a league awards points and orders its table.
+///
+/// Teams are ordered by points, then by each of `tiebreaks` in turn, and
+/// then by name, if they're still tied.
+///
+/// ```
+/// # use tournament::*;
+/// let rules = LeagueRules {
+///     points: PointsTable::TWO_FOR_A_WIN,
+///     bonuses: vec![Bonus::AwayWin(1)],
+///     tiebreaks: vec![Tiebreak::HeadToHead, Tiebreak::Wins],
+/// };
+/// let input = "\
+/// Allegoric Alaskans;Blithering Badgers;loss
+/// Allegoric Alaskans;Courageous Californians;win
+/// Courageous Californians;Blithering Badgers;draw";
+///
+/// assert_eq!(
+///     tally_with(input, &rules),
+///     "\
+/// Team                           | MP |  W |  D |  L |  P
+/// Blithering Badgers             |  2 |  1 |  1 |  0 |  4
+/// Allegoric Alaskans             |  2 |  1 |  0 |  1 |  2
+/// Courageous Californians        |  2 |  0 |  1 |  1 |  1",
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct LeagueRules {
+    pub points: PointsTable,
+    pub bonuses: Vec<Bonus>,
+    pub tiebreaks: Vec<Tiebreak>,
+}
+
+impl LeagueRules {
+    /// Everything a team gets for playing at home, or not, to `outcome`.
+    pub fn points(&self, home: bool, outcome: Outcome) -> u32 {
+        self.points.points(outcome)
+            + self
+                .bonuses
+                .iter()
+                .map(|b| b.points(home, outcome))
+                .sum::<u32>()
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{Match, Outcome, Score, Standings};
+
+/// A match that's yet to be played.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Fixture<'a> {
+    pub home: &'a str,
+    pub away: &'a str,
+}
+
+impl<'a> Fixture<'a> {
+    /// The match, once it's ended in `outcome` for the home team.
+    pub fn with_outcome(self, outcome: Outcome) -> Match<'a> {
+        Match {
+            home: self.home,
+            away: self.away,
+            outcome,
+            score: None,
+        }
+    }
+
+    pub fn with_score(self, score: Score) -> Match<'a> {
+        Match {
+            home: self.home,
+            away: self.away,
+            outcome: score.outcome(),
+            score: Some(score),
+        }
+    }
+
+    fn reversed(self) -> Self {
+        Fixture {
+            home: self.away,
+            away: self.home,
+        }
+    }
+}
+
+/// Rounds in which every team plays every other team once, made with the
+/// circle method. With an odd number of teams, one sits out each round.
+///
+/// ```
+/// # use tournament::*;
+/// let rounds = round_robin(&["Alaskans", "Badgers", "Californians", "Donkeys"]);
+/// assert_eq!(rounds.len(), 3);
+/// assert_eq!(
+///     rounds[0],
+///     vec![
+///         Fixture { home: "Alaskans", away: "Donkeys" },
+///         Fixture { home: "Badgers", away: "Californians" },
+///     ],
+/// );
+///
+/// let rounds = round_robin(&["Alaskans", "Badgers", "Californians"]);
+/// assert_eq!(rounds.len(), 3);
+/// assert!(rounds.iter().all(|r| r.len() == 1));
+/// ```
+pub fn round_robin<'a>(teams: &[&'a str]) -> Vec<Vec<Fixture<'a>>> {
+    if teams.len() < 2 {
+        return vec![];
+    }
+
+    let mut slots: Vec<Option<&'a str>> = teams.iter().copied().map(Some).collect();
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+
+    let n = slots.len();
+    let mut rounds = vec![];
+    for round in 0..n - 1 {
+        let mut fixtures = vec![];
+        for i in 0..n / 2 {
+            // Everyone spends about half the rounds in the first half of the
+            // circle, at home, and the first team alternates.
+            if let (Some(a), Some(b)) = (slots[i], slots[n - 1 - i]) {
+                let fixture = Fixture { home: a, away: b };
+                fixtures.push(if i > 0 || round.is_multiple_of(2) {
+                    fixture
+                } else {
+                    fixture.reversed()
+                });
+            }
+        }
+        rounds.push(fixtures);
+
+        // The first team stays put, and everyone else goes round it.
+        slots[1..].rotate_right(1);
+    }
+    rounds
+}
+
+/// A `round_robin`, and then it again with home and away swapped.
+///
+/// ```
+/// # use tournament::*;
+/// let rounds = double_round_robin(&["Alaskans", "Badgers"]);
+/// assert_eq!(
+///     rounds,
+///     vec![
+///         vec![Fixture { home: "Alaskans", away: "Badgers" }],
+///         vec![Fixture { home: "Badgers", away: "Alaskans" }],
+///     ],
+/// );
+/// ```
+pub fn double_round_robin<'a>(teams: &[&'a str]) -> Vec<Vec<Fixture<'a>>> {
+    let first_half = round_robin(teams);
+    let second_half: Vec<Vec<Fixture<'a>>> = first_half
+        .iter()
+        .map(|r| r.iter().map(|f| f.reversed()).collect())
+        .collect();
+
+    first_half.into_iter().chain(second_half).collect()
+}
+
+/// The next round of a Swiss-system tournament.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct SwissRound<'a> {
+    pub fixtures: Vec<Fixture<'a>>,
+    /// Who sits out, with an odd number of teams.
+    pub bye: Option<&'a str>,
+}
+
+/// Pairs each team with the team closest to it in `standings` that it
+/// hasn't played yet, the higher placed one at home. Teams that aren't in
+/// the standings yet come last, in the order they're given.
+///
+/// With an odd number of teams, the bye goes to the lowest placed team that
+/// has played the most matches, so no one sits out twice before everyone has
+/// sat out once.
+///
+/// Returns `None` if there's no way to pair the teams without a rematch.
+///
+/// ```
+/// # use tournament::*;
+/// let teams = ["Alaskans", "Badgers", "Californians", "Donkeys"];
+/// let standings: Standings = "\
+/// Alaskans;Badgers;win
+/// Californians;Donkeys;win"
+///     .parse()
+///     .unwrap();
+///
+/// let round = swiss_round(&teams, &standings).unwrap();
+/// assert_eq!(
+///     round.fixtures,
+///     vec![
+///         Fixture { home: "Alaskans", away: "Californians" },
+///         Fixture { home: "Badgers", away: "Donkeys" },
+///     ],
+/// );
+/// assert_eq!(round.bye, None);
+///
+/// let round = swiss_round(&teams[..3], &standings).unwrap();
+/// assert_eq!(round.fixtures, vec![Fixture { home: "Alaskans", away: "Californians" }]);
+/// assert_eq!(round.bye, Some("Badgers"));
+///
+/// assert_eq!(swiss_round(&teams[..2], &standings), None);
+/// ```
+pub fn swiss_round<'a>(teams: &[&'a str], standings: &Standings) -> Option<SwissRound<'a>> {
+    let mut ranked = teams.to_vec();
+    ranked.sort_by_key(|t| standings.position(t).unwrap_or(usize::MAX));
+
+    if ranked.len().is_multiple_of(2) {
+        return pair(&ranked, standings).map(|fixtures| SwissRound {
+            fixtures,
+            bye: None,
+        });
+    }
+
+    let played = |t: &str| standings.get(t).map_or(0, |r| r.played);
+    let mut byes: Vec<usize> = (0..ranked.len()).rev().collect();
+    byes.sort_by_key(|i| std::cmp::Reverse(played(ranked[*i])));
+    byes.into_iter().find_map(|i| {
+        let mut rest = ranked.clone();
+        let bye = rest.remove(i);
+        pair(&rest, standings).map(|fixtures| SwissRound {
+            fixtures,
+            bye: Some(bye),
+        })
+    })
+}
+
+/// Pairs off `unpaired`, which are in order of standing: each team, from the
+/// top, with the closest team below it that leaves a way to pair off the
+/// rest without a rematch.
+fn pair<'a>(unpaired: &[&'a str], standings: &Standings) -> Option<Vec<Fixture<'a>>> {
+    let can_meet = |a: &str, b: &str| a != b && !standings.have_played(a, b);
+    let can_pair = |teams: &[&str]| {
+        let allowed: Vec<Vec<bool>> = teams
+            .iter()
+            .map(|a| teams.iter().map(|b| can_meet(a, b)).collect())
+            .collect();
+        has_perfect_matching(&allowed)
+    };
+    if !can_pair(unpaired) {
+        return None;
+    }
+
+    let mut rest = unpaired.to_vec();
+    let mut fixtures = vec![];
+    while !rest.is_empty() {
+        let home = rest.remove(0);
+        let i = (0..rest.len())
+            .find(|i| {
+                let mut others = rest.clone();
+                let away = others.remove(*i);
+                can_meet(home, away) && can_pair(&others)
+            })
+            .expect("the rest can be paired off");
+        fixtures.push(Fixture {
+            home,
+            away: rest.remove(i),
+        });
+    }
+    Some(fixtures)
+}
+
+/// Whether everyone can be paired off with someone they're `allowed` to be
+/// paired with, found with Edmonds' blossom algorithm.
+fn has_perfect_matching(allowed: &[Vec<bool>]) -> bool {
+    let n = allowed.len();
+    if !n.is_multiple_of(2) {
+        return false;
+    }
+    let mut matching = Matching {
+        allowed,
+        mate: vec![None; n],
+        parent: vec![None; n],
+        base: (0..n).collect(),
+    };
+    (0..n).all(|v| matching.mate[v].is_some() || matching.augment(v))
+}
+
+struct Matching<'a> {
+    allowed: &'a [Vec<bool>],
+    mate: Vec<Option<usize>>,
+    /// Where each vertex was reached from in the search from a root.
+    parent: Vec<Option<usize>>,
+    /// The base of the blossom each vertex is in.
+    base: Vec<usize>,
+}
+
+impl Matching<'_> {
+    /// Looks for a path from `root`, which is unmatched, to another
+    /// unmatched vertex, and matches them along it if there is one.
+    fn augment(&mut self, root: usize) -> bool {
+        let n = self.allowed.len();
+        self.parent = vec![None; n];
+        self.base = (0..n).collect();
+        let mut visited = vec![false; n];
+        visited[root] = true;
+        let mut queue = std::collections::VecDeque::from([root]);
+
+        while let Some(v) = queue.pop_front() {
+            for to in 0..n {
+                if !self.allowed[v][to] || self.base[v] == self.base[to] || self.mate[v] == Some(to)
+                {
+                    continue;
+                }
+                let odd_cycle =
+                    to == root || self.mate[to].is_some_and(|m| self.parent[m].is_some());
+                if odd_cycle {
+                    let base = self.common_ancestor(v, to);
+                    let mut in_blossom = vec![false; n];
+                    self.mark_path(v, base, to, &mut in_blossom);
+                    self.mark_path(to, base, v, &mut in_blossom);
+                    for i in 0..n {
+                        if in_blossom[self.base[i]] {
+                            self.base[i] = base;
+                            if !visited[i] {
+                                visited[i] = true;
+                                queue.push_back(i);
+                            }
+                        }
+                    }
+                } else if self.parent[to].is_none() {
+                    self.parent[to] = Some(v);
+                    let Some(next) = self.mate[to] else {
+                        self.flip(to);
+                        return true;
+                    };
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Matches along the path found to the unmatched vertex `end`.
+    fn flip(&mut self, end: usize) {
+        let mut v = Some(end);
+        while let Some(u) = v {
+            let p = self.parent[u].expect("the path leads back to the root");
+            v = self.mate[p];
+            self.mate[u] = Some(p);
+            self.mate[p] = Some(u);
+        }
+    }
+
+    fn common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
+        let mut seen = vec![false; self.allowed.len()];
+        loop {
+            a = self.base[a];
+            seen[a] = true;
+            match self.mate[a] {
+                Some(m) => a = self.parent[m].expect("matched vertices have parents"),
+                None => break,
+            }
+        }
+        loop {
+            b = self.base[b];
+            if seen[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b].expect("the root is an ancestor")]
+                .expect("matched vertices have parents");
+        }
+    }
+
+    /// Marks the blossoms on the path from `v` down to `base`, pointing it
+    /// back along the other side of the cycle through `child`.
+    fn mark_path(&mut self, mut v: usize, base: usize, mut child: usize, in_blossom: &mut [bool]) {
+        while self.base[v] != base {
+            let m = self.mate[v].expect("vertices in a blossom are matched");
+            in_blossom[self.base[v]] = true;
+            in_blossom[self.base[m]] = true;
+            self.parent[v] = Some(child);
+            child = m;
+            v = self.parent[m].expect("matched vertices have parents");
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use std::{cmp::Reverse, collections::HashMap, fmt::Display, io::BufRead, str::FromStr, sync::Arc};
+
+use crate::{
+    parse_matches, read_matches, InvalidLines, LeagueRules, Match, NotRecorded, Outcome, ReadError,
+    Renderer, Score, Text, Tiebreak,
+};
+
+/// One team's results so far.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct TeamRecord {
+    pub team: String,
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub points: u32,
+    /// Only from matches with a `Score`.
+    pub goals_for: u32,
+    pub goals_against: u32,
+}
+
+impl TeamRecord {
+    pub fn new(team: &str) -> Self {
+        TeamRecord {
+            team: team.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn goal_difference(&self) -> i64 {
+        self.goals_for as i64 - self.goals_against as i64
+    }
+
+    /// Counts one more match, that ended in `outcome` and `points` for this
+    /// team, and the goals it scored and conceded, if they're known.
+    pub fn record(&mut self, outcome: Outcome, points: u32, goals: Option<(u32, u32)>) {
+        self.played += 1;
+        match outcome {
+            Outcome::Win => self.won += 1,
+            Outcome::Draw => self.drawn += 1,
+            Outcome::Loss => self.lost += 1,
+        }
+        self.points += points;
+        if let Some((scored, conceded)) = goals {
+            self.goals_for += scored;
+            self.goals_against += conceded;
+        }
+    }
+
+    /// Undoes `record`.
+    fn unrecord(&mut self, outcome: Outcome, points: u32, goals: Option<(u32, u32)>) {
+        self.played -= 1;
+        match outcome {
+            Outcome::Win => self.won -= 1,
+            Outcome::Draw => self.drawn -= 1,
+            Outcome::Loss => self.lost -= 1,
+        }
+        self.points -= points;
+        if let Some((scored, conceded)) = goals {
+            self.goals_for -= scored;
+            self.goals_against -= conceded;
+        }
+    }
+}
+
+/// What a team has done against one opponent.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+struct Meetings {
+    played: u32,
+    points: u32,
+}
+
+/// Every team's record, best first: by points, then by the `LeagueRules`'
+/// tiebreaks.
+///
+/// ```
+/// # use tournament::*;
+/// let standings: Standings = "\
+/// Allegoric Alaskans;Blithering Badgers;win
+/// Blithering Badgers;Courageous Californians;draw
+/// Courageous Californians;Allegoric Alaskans;loss"
+///     .parse()
+///     .unwrap();
+///
+/// let alaskans = &standings.records()[0];
+/// assert_eq!(alaskans.team, "Allegoric Alaskans");
+/// assert_eq!((alaskans.played, alaskans.won, alaskans.points), (2, 2, 6));
+///
+/// let badgers = standings.get("Blithering Badgers").unwrap();
+/// assert_eq!((badgers.drawn, badgers.lost, badgers.points), (1, 1, 1));
+/// assert_eq!(standings.position("Courageous Californians"), Some(2));
+/// assert_eq!(standings.head_to_head("Blithering Badgers", "Courageous Californians"), 1);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Standings {
+    rules: LeagueRules,
+    records: Vec<TeamRecord>,
+    /// Where each team is in `records`. Its keys are the only copies of the
+    /// teams' names besides the records' own, shared with `head_to_head` and
+    /// `results`, so counting a result that's been counted before doesn't
+    /// allocate.
+    positions: HashMap<Arc<str>, usize>,
+    /// What each team has done against each other team.
+    head_to_head: HashMap<Arc<str>, HashMap<Arc<str>, Meetings>>,
+    /// How many times each result has been counted, so that only those can
+    /// be retracted.
+    results: HashMap<Counted, u32>,
+}
+
+/// A result, by home team, away team, outcome and score.
+type Counted = (Arc<str>, Arc<str>, Outcome, Option<Score>);
+
+/// One team's side of a match.
+struct Side<'a> {
+    team: &'a str,
+    opponent: &'a str,
+    home: bool,
+    outcome: Outcome,
+    /// Scored and conceded.
+    goals: Option<(u32, u32)>,
+}
+
+fn sides<'a>(m: &Match<'a>) -> [Side<'a>; 2] {
+    [
+        Side {
+            team: m.home,
+            opponent: m.away,
+            home: true,
+            outcome: m.outcome,
+            goals: m.score.map(|s| (s.home, s.away)),
+        },
+        Side {
+            team: m.away,
+            opponent: m.home,
+            home: false,
+            outcome: m.outcome.flip(),
+            goals: m.score.map(|s| (s.away, s.home)),
+        },
+    ]
+}
+
+impl Standings {
+    /// Standings with no matches yet, to `add` them to one at a time.
+    ///
+    /// ```
+    /// # use tournament::*;
+    /// let mut standings = Standings::new(LeagueRules::default());
+    /// let first = Match::try_from("Allegoric Alaskans;Blithering Badgers;win").unwrap();
+    /// let typo = Match::try_from("Blithering Badgers;Courageous Califonians;win").unwrap();
+    /// standings.add(&first);
+    /// standings.add(&typo);
+    /// assert_eq!(standings.records()[0].team, "Allegoric Alaskans");
+    /// assert_eq!(standings.position("Courageous Califonians"), Some(2));
+    ///
+    /// standings.retract(&typo).unwrap();
+    /// assert_eq!(standings.get("Courageous Califonians"), None);
+    /// assert_eq!(standings.get("Blithering Badgers").unwrap().played, 1);
+    /// assert!(standings.retract(&typo).is_err());
+    ///
+    /// standings.add(&Match::try_from("Blithering Badgers;Courageous Californians;win").unwrap());
+    /// assert_eq!(standings.records()[0].team, "Allegoric Alaskans");
+    /// assert_eq!(standings.records()[1].team, "Blithering Badgers");
+    /// ```
+    pub fn new(rules: LeagueRules) -> Self {
+        Standings {
+            rules,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_matches<'a>(matches: impl IntoIterator<Item = Match<'a>>) -> Self {
+        Standings::from_matches_with(matches, &LeagueRules::default())
+    }
+
+    pub fn from_matches_with<'a>(
+        matches: impl IntoIterator<Item = Match<'a>>,
+        rules: &LeagueRules,
+    ) -> Self {
+        let mut standings = Standings::new(rules.clone());
+        for m in matches {
+            standings.count(&m);
+        }
+        standings.sort();
+        standings
+    }
+
+    /// Like `parse`, but with `rules` rather than the default ones.
+    pub fn parse_with(s: &str, rules: &LeagueRules) -> Result<Self, InvalidLines> {
+        Ok(Standings::from_matches_with(
+            parse_matches(s).strict()?,
+            rules,
+        ))
+    }
+
+    /// Tallies the matches in `reader` a line at a time, for inputs too big
+    /// to read into a `&str` first. Only one line is in memory at a time,
+    /// and each team's name is kept once however many matches it plays. What
+    /// is kept is a record per team, the meetings of each pair of teams that
+    /// have played, a count of each different result, and the text of every
+    /// line that isn't a match.
+    ///
+    /// ```
+    /// # use tournament::*;
+    /// use std::io::Cursor;
+    ///
+    /// let archive = Cursor::new("\
+    /// Allegoric Alaskans;Blithering Badgers;win\r
+    /// Blithering Badgers;Courageous Californians;draw\r
+    /// ");
+    /// let standings = Standings::read(archive).unwrap();
+    /// assert_eq!(standings.records()[0].team, "Allegoric Alaskans");
+    /// assert_eq!(standings.get("Courageous Californians").unwrap().points, 1);
+    ///
+    /// let bad = Cursor::new("Allegoric Alaskans;Blithering Badgers;won");
+    /// assert!(matches!(Standings::read(bad), Err(ReadError::Invalid(_))));
+    /// ```
+    pub fn read(reader: impl BufRead) -> Result<Self, ReadError> {
+        Standings::read_with(reader, &LeagueRules::default())
+    }
+
+    /// Like `read`, but with `rules` rather than the default ones.
+    pub fn read_with(reader: impl BufRead, rules: &LeagueRules) -> Result<Self, ReadError> {
+        let mut standings = Standings::new(rules.clone());
+        let errors = read_matches(reader, |m| standings.count(&m))?;
+        if !errors.is_empty() {
+            return Err(ReadError::Invalid(InvalidLines(errors)));
+        }
+        standings.sort();
+        Ok(standings)
+    }
+
+    /// Counts one more match.
+    pub fn add(&mut self, m: &Match) {
+        self.count(m);
+        self.sort();
+    }
+
+    /// Takes back a match that was counted by mistake: the same teams, the
+    /// same way round, with the same outcome and score. Teams with no other
+    /// matches leave the standings.
+    pub fn retract(&mut self, m: &Match) -> Result<(), NotRecorded> {
+        let not_recorded = || NotRecorded {
+            home: m.home.to_string(),
+            away: m.away.to_string(),
+        };
+        let (Some((home, _)), Some((away, _))) = (
+            self.positions.get_key_value(m.home),
+            self.positions.get_key_value(m.away),
+        ) else {
+            return Err(not_recorded());
+        };
+        let key = (home.clone(), away.clone(), m.outcome, m.score);
+        let count = self.results.get_mut(&key).ok_or_else(not_recorded)?;
+        *count -= 1;
+        if *count == 0 {
+            self.results.remove(&key);
+        }
+
+        for Side {
+            team,
+            opponent,
+            home,
+            outcome,
+            goals,
+        } in sides(m)
+        {
+            let points = self.rules.points(home, outcome);
+            let opponents = self.head_to_head.get_mut(team).expect("they've met");
+            let meetings = opponents.get_mut(opponent).expect("they've met");
+            meetings.played -= 1;
+            meetings.points -= points;
+            if meetings.played == 0 {
+                opponents.remove(opponent);
+            }
+
+            let i = self.positions[team];
+            self.records[i].unrecord(outcome, points, goals);
+            if self.records[i].played == 0 {
+                self.records.swap_remove(i);
+                self.positions.remove(team);
+                self.head_to_head.remove(team);
+                if let Some(moved) = self.records.get(i) {
+                    *self
+                        .positions
+                        .get_mut(moved.team.as_str())
+                        .expect("it's there") = i;
+                }
+            }
+        }
+        self.sort();
+        Ok(())
+    }
+
+    /// Counts `m`, without putting the records back in order.
+    fn count(&mut self, m: &Match) {
+        for Side {
+            team,
+            opponent,
+            home,
+            outcome,
+            goals,
+        } in sides(m)
+        {
+            let points = self.rules.points(home, outcome);
+            let (team, i) = self.intern(team);
+            let (opponent, _) = self.intern(opponent);
+            self.records[i].record(outcome, points, goals);
+
+            let meetings = self
+                .head_to_head
+                .entry(team)
+                .or_default()
+                .entry(opponent)
+                .or_default();
+            meetings.played += 1;
+            meetings.points += points;
+        }
+
+        let (home, _) = self.intern(m.home);
+        let (away, _) = self.intern(m.away);
+        *self
+            .results
+            .entry((home, away, m.outcome, m.score))
+            .or_default() += 1;
+    }
+
+    /// The shared copy of `team`'s name, and where it is in `records`,
+    /// adding it if it's new.
+    fn intern(&mut self, team: &str) -> (Arc<str>, usize) {
+        if let Some((name, i)) = self.positions.get_key_value(team) {
+            return (name.clone(), *i);
+        }
+        let name: Arc<str> = team.into();
+        self.records.push(TeamRecord::new(team));
+        self.positions.insert(name.clone(), self.records.len() - 1);
+        (name, self.records.len() - 1)
+    }
+
+    fn meetings(&self, team: &str, opponent: &str) -> Meetings {
+        self.head_to_head
+            .get(team)
+            .and_then(|o| o.get(opponent))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn rules(&self) -> &LeagueRules {
+        &self.rules
+    }
+
+    /// Best first.
+    pub fn records(&self) -> &[TeamRecord] {
+        &self.records
+    }
+
+    pub fn get(&self, team: &str) -> Option<&TeamRecord> {
+        Some(&self.records[self.position(team)?])
+    }
+
+    /// Where `team` is in the standings, counting from 0.
+    pub fn position(&self, team: &str) -> Option<usize> {
+        self.positions.get(team).copied()
+    }
+
+    pub fn render(&self, renderer: &impl Renderer) -> String {
+        renderer.render(self)
+    }
+
+    /// The points `team` has taken off `opponent`.
+    pub fn head_to_head(&self, team: &str, opponent: &str) -> u32 {
+        self.meetings(team, opponent).points
+    }
+
+    pub fn have_played(&self, team: &str, opponent: &str) -> bool {
+        self.meetings(team, opponent).played > 0
+    }
+
+    /// Puts the records back in order. They're mostly in order already, after
+    /// a match or two, which the sort takes advantage of.
+    fn sort(&mut self) {
+        let mut records = std::mem::take(&mut self.records);
+        records.sort_by_key(|r| Reverse(r.points));
+        for tied in records.chunk_by_mut(|a, b| a.points == b.points) {
+            self.break_ties(tied, &self.rules.tiebreaks);
+        }
+        self.records = records;
+
+        for (i, r) in self.records.iter().enumerate() {
+            if let Some(position) = self.positions.get_mut(r.team.as_str()) {
+                *position = i;
+            }
+        }
+    }
+
+    /// Orders teams that are tied so far by the first of `tiebreaks`, and
+    /// any that are still tied by the rest.
+    fn break_ties(&self, tied: &mut [TeamRecord], tiebreaks: &[Tiebreak]) {
+        if tied.len() < 2 {
+            return;
+        }
+
+        // Names are never tied, so they settle everything.
+        let (tiebreak, rest) = match tiebreaks.split_first() {
+            None | Some((Tiebreak::Name, _)) => {
+                tied.sort_by(|a, b| a.team.cmp(&b.team));
+                return;
+            }
+            Some(first) => first,
+        };
+
+        // Higher is better.
+        let teams: Vec<String> = tied.iter().map(|r| r.team.clone()).collect();
+        let key = |r: &TeamRecord| -> i64 {
+            match tiebreak {
+                Tiebreak::HeadToHead => teams
+                    .iter()
+                    .map(|o| self.head_to_head(&r.team, o) as i64)
+                    .sum(),
+                Tiebreak::Wins => r.won as i64,
+                Tiebreak::GoalDifference => r.goal_difference(),
+                Tiebreak::GoalsFor => r.goals_for as i64,
+                Tiebreak::Name => unreachable!(),
+            }
+        };
+
+        tied.sort_by_cached_key(|r| Reverse(key(r)));
+        for still_tied in tied.chunk_by_mut(|a, b| key(a) == key(b)) {
+            self.break_ties(still_tied, rest);
+        }
+    }
+}
+
+impl FromStr for Standings {
+    type Err = InvalidLines;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Standings::parse_with(s, &LeagueRules::default())
+    }
+}
+
+/// Writes `Text`.
+impl Display for Standings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Text.render(self))
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{InvalidLines, LineError, Match};
+
+/// Why `Standings::read` failed.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Invalid(InvalidLines),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "couldn't read matches: {}", e),
+            ReadError::Invalid(lines) => write!(f, "{}", lines),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Invalid(lines) => Some(lines),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+/// Like `parse_matches`, but a line at a time from `reader`, so the input
+/// never has to be in memory all at once. Each match is handed to `f`, and
+/// borrows from a buffer that's reused for the next line.
+///
+/// Returns the lines that aren't `Match`es, or the first error reading.
+///
+/// ```
+/// # use tournament::*;
+/// let input = "\
+/// Allegoric Alaskans;Blithering Badgers;win
+/// Allegoric Alaskans;Blithering Badgers
+/// Blithering Badgers;Courageous Californians;1-1
+/// ";
+///
+/// let mut ratings = Ratings::new(Elo::default());
+/// let errors = read_matches(input.as_bytes(), |m| ratings.record(&m)).unwrap();
+/// assert_eq!(ratings.history("Blithering Badgers").unwrap().len(), 3);
+/// assert_eq!(errors[0].line, 2);
+/// ```
+pub fn read_matches<R: BufRead>(
+    mut reader: R,
+    mut f: impl FnMut(Match<'_>),
+) -> io::Result<Vec<LineError>> {
+    let mut errors = vec![];
+    let mut buffer = String::new();
+    for i in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        let line = buffer.trim_end_matches(['\n', '\r']);
+        if line.is_empty() {
+            continue;
+        }
+        match Match::try_from(line) {
+            Ok(m) => f(m),
+            Err(error) => errors.push(LineError {
+                line: i + 1,
+                text: line.to_string(),
+                error,
+            }),
+        }
+    }
+    Ok(errors)
+}
@@ -0,0 +1,180 @@
+use tournament::*;
+
+const SEEDS: [&str; 8] = ["1", "2", "3", "4", "5", "6", "7", "8"];
+
+fn fixtures(bracket: &Bracket) -> Vec<(String, String)> {
+    bracket
+        .fixtures()
+        .iter()
+        .map(|f| (f.home.to_string(), f.away.to_string()))
+        .collect()
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(h, a)| (h.to_string(), a.to_string()))
+        .collect()
+}
+
+#[test]
+fn top_seeds_meet_as_late_as_possible() {
+    let mut bracket = Bracket::new(&SEEDS).unwrap();
+    assert_eq!(
+        fixtures(&bracket),
+        pairs(&[("1", "8"), ("4", "5"), ("2", "7"), ("3", "6")])
+    );
+
+    for seed in ["1", "4", "2", "3"] {
+        bracket.advance(seed).unwrap();
+    }
+    assert_eq!(fixtures(&bracket), pairs(&[("1", "4"), ("2", "3")]));
+    bracket.advance("1").unwrap();
+    bracket.advance("2").unwrap();
+    assert_eq!(fixtures(&bracket), pairs(&[("1", "2")]));
+    assert_eq!(bracket.champion(), None);
+    bracket.advance("2").unwrap();
+    assert_eq!(bracket.champion(), Some("2"));
+    assert!(fixtures(&bracket).is_empty());
+}
+
+#[test]
+fn top_seeds_get_the_byes() {
+    let bracket = Bracket::new(&SEEDS[..5]).unwrap();
+    assert_eq!(fixtures(&bracket), pairs(&[("4", "5"), ("2", "3")]));
+    assert_eq!(
+        bracket.to_string(),
+        "\
+Quarter-finals
+  1 v (bye): 1
+  4 v 5
+  2 v (bye): 2
+  3 v (bye): 3
+Semi-finals
+  1 v ?
+  2 v 3
+Final
+  ? v ?"
+    );
+}
+
+#[test]
+fn a_larger_bracket_names_its_rounds_by_size() {
+    let seeds: Vec<String> = (1..=9).map(|s| s.to_string()).collect();
+    let seeds: Vec<&str> = seeds.iter().map(String::as_str).collect();
+    let bracket = Bracket::new(&seeds).unwrap();
+    assert!(bracket
+        .to_string()
+        .starts_with("Round of 16\n  1 v (bye): 1\n  8 v 9\n"));
+    assert_eq!(bracket.rounds().len(), 4);
+}
+
+#[test]
+fn results_must_fit_the_bracket() {
+    let mut bracket = Bracket::new(&SEEDS[..4]).unwrap();
+    assert_eq!(
+        bracket.record(&Match::try_from("1;4;draw").unwrap()),
+        Err(BracketError::Draw)
+    );
+    assert_eq!(
+        bracket.record(&Match::try_from("1;2;win").unwrap()),
+        Err(BracketError::NotATie("1".to_string(), "2".to_string()))
+    );
+    assert_eq!(
+        bracket.advance("5"),
+        Err(BracketError::NotInPlay("5".to_string()))
+    );
+    bracket
+        .record(&Match::try_from("4;1;win").unwrap())
+        .unwrap();
+    assert_eq!(
+        bracket.advance("1"),
+        Err(BracketError::NotInPlay("1".to_string()))
+    );
+}
+
+#[test]
+fn bad_seedings() {
+    assert_eq!(Bracket::new(&["1"]), Err(BracketError::NotEnoughTeams));
+    assert_eq!(
+        Bracket::new(&["1", "2", "1"]),
+        Err(BracketError::DuplicateTeam("1".to_string()))
+    );
+
+    let a: Standings = "1;2;win\n2;3;win".parse().unwrap();
+    let b: Standings = "4;5;win".parse().unwrap();
+    assert_eq!(
+        Bracket::from_groups(&[a, b], 3),
+        Err(BracketError::SmallGroup(1))
+    );
+}
+
+#[test]
+fn group_mates_dont_meet_in_the_first_round() {
+    for (count, qualifiers) in [(3, 2), (3, 4), (5, 2), (6, 3)] {
+        let groups: Vec<Standings> = (0..count)
+            .map(|g| {
+                let team = |i: usize| format!("{}{}", (b'A' + g as u8) as char, i);
+                let results: Vec<String> = (1..qualifiers)
+                    .map(|i| format!("{};{};win", team(i), team(i + 1)))
+                    .collect();
+                results.join("\n").parse().unwrap()
+            })
+            .collect();
+
+        let bracket = Bracket::from_groups(&groups, qualifiers).unwrap();
+        let first = &bracket.rounds()[0];
+        let teams = first
+            .iter()
+            .flat_map(|t| [&t.home, &t.away])
+            .filter(|s| matches!(s, Slot::Team(_)));
+        assert_eq!(teams.count(), count * qualifiers);
+        for tie in first {
+            if let (Slot::Team(home), Slot::Team(away)) = (&tie.home, &tie.away) {
+                assert_ne!(home[..1], away[..1], "{} groups of {}", count, qualifiers);
+            }
+        }
+    }
+}
+
+#[test]
+fn too_few_qualifiers_for_a_bracket() {
+    let group: Standings = "1;2;win".parse().unwrap();
+    assert_eq!(
+        Bracket::from_groups(&[], 2),
+        Err(BracketError::NotEnoughTeams)
+    );
+    assert_eq!(
+        Bracket::from_groups(std::slice::from_ref(&group), 1),
+        Err(BracketError::NotEnoughTeams)
+    );
+    assert_eq!(
+        Bracket::from_groups(&[group.clone(), group], 0),
+        Err(BracketError::NotEnoughTeams)
+    );
+}
+
+#[test]
+fn group_mates_meet_when_it_cant_be_helped() {
+    let group: Standings = "A1;A2;win\nA2;A3;win\nA3;A4;win".parse().unwrap();
+    let bracket = Bracket::from_groups(&[group], 4).unwrap();
+    assert_eq!(fixtures(&bracket), pairs(&[("A1", "A4"), ("A2", "A3")]));
+}
+
+#[test]
+fn many_groups_are_seeded_apart_quickly() {
+    let groups: Vec<Standings> = (0..24)
+        .map(|g| {
+            let team = |i: usize| format!("{:02}-{}", g, i);
+            let results: Vec<String> = (1..4)
+                .map(|i| format!("{};{};win", team(i), team(i + 1)))
+                .collect();
+            results.join("\n").parse().unwrap()
+        })
+        .collect();
+
+    let bracket = Bracket::from_groups(&groups, 4).unwrap();
+    for (home, away) in fixtures(&bracket) {
+        assert_ne!(home[..2], away[..2]);
+    }
+}
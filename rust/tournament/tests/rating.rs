@@ -0,0 +1,101 @@
+use tournament::*;
+
+const SEASON: &str = "\
+Allegoric Alaskans;Blithering Badgers;3-1
+Devastating Donkeys;Courageous Californians;draw
+Devastating Donkeys;Allegoric Alaskans;win
+Courageous Californians;Blithering Badgers;0-2
+Blithering Badgers;Devastating Donkeys;loss
+Allegoric Alaskans;Courageous Californians;win";
+
+fn season() -> Vec<Match<'static>> {
+    parse_matches(SEASON).strict().unwrap()
+}
+
+#[test]
+fn elo_moves_points_between_teams() {
+    let ratings = Ratings::from_matches(Elo::default(), season());
+    let total: f64 = ratings.teams().map(|t| ratings.rating(t).unwrap()).sum();
+    assert!((total - 4.0 * 1500.0).abs() < 1e-9);
+
+    let ranked: Vec<&str> = ratings.ranked().iter().map(|(t, _)| *t).collect();
+    assert_eq!(
+        ranked,
+        [
+            "Devastating Donkeys",
+            "Allegoric Alaskans",
+            "Blithering Badgers",
+            "Courageous Californians",
+        ]
+    );
+}
+
+#[test]
+fn histories_follow_the_order_of_the_matches() {
+    let ratings = Ratings::from_matches(Elo::default(), season());
+    let alaskans = ratings.history("Allegoric Alaskans").unwrap();
+    assert_eq!(alaskans.len(), 4);
+    assert_eq!(alaskans[0], 1500.0);
+    assert!(alaskans[1] > alaskans[0]);
+    assert!(alaskans[2] < alaskans[1]);
+    assert!(alaskans[3] > alaskans[2]);
+}
+
+#[test]
+fn a_bigger_k_factor_moves_ratings_further() {
+    let small = Ratings::from_matches(Elo::default(), season());
+    let big = Ratings::from_matches(
+        Elo {
+            k: 40.0,
+            ..Elo::default()
+        },
+        season(),
+    );
+    let spread = |r: &Ratings<Elo>| {
+        let ranked = r.ranked();
+        ranked[0].1 - ranked[ranked.len() - 1].1
+    };
+    assert!(spread(&big) > spread(&small));
+}
+
+#[test]
+fn home_wins_are_worth_less_with_home_advantage() {
+    let m = Match::try_from("Allegoric Alaskans;Blithering Badgers;win").unwrap();
+    let gain = |elo: Elo| {
+        let ratings = Ratings::from_matches(elo, [m]);
+        ratings.rating("Allegoric Alaskans").unwrap() - 1500.0
+    };
+    assert!(
+        gain(Elo {
+            home_advantage: 100.0,
+            ..Elo::default()
+        }) < gain(Elo::default())
+    );
+}
+
+#[test]
+fn glicko_gets_surer_with_every_match() {
+    let ratings = Ratings::from_matches(Glicko::default(), season());
+    for team in ratings.teams() {
+        let history = ratings.history(team).unwrap();
+        assert!(history.windows(2).all(|w| w[1].deviation < w[0].deviation));
+    }
+    assert_eq!(ratings.ranked()[0].0, "Devastating Donkeys");
+}
+
+#[test]
+fn glicko_deviation_grows_back_between_matches() {
+    let m = Match::try_from("Allegoric Alaskans;Blithering Badgers;draw").unwrap();
+    let steady = Ratings::from_matches(Glicko::default(), [m, m]);
+    let growing = Ratings::from_matches(
+        Glicko {
+            deviation_growth: 100.0,
+            ..Glicko::default()
+        },
+        [m, m],
+    );
+    assert!(
+        growing.rating("Allegoric Alaskans").unwrap().deviation
+            > steady.rating("Allegoric Alaskans").unwrap().deviation
+    );
+}
@@ -0,0 +1,114 @@
+use std::collections::{HashMap, HashSet};
+use tournament::*;
+
+const TEAMS: [&str; 7] = [
+    "Allegoric Alaskans",
+    "Blithering Badgers",
+    "Courageous Californians",
+    "Devastating Donkeys",
+    "Eloquent Elephants",
+    "Fabulous Flamingos",
+    "Gregarious Gorillas",
+];
+
+fn pair<'a>(f: &Fixture<'a>) -> (&'a str, &'a str) {
+    (f.home.min(f.away), f.home.max(f.away))
+}
+
+#[test]
+fn round_robin_pairs_everyone_once() {
+    for n in 2..=TEAMS.len() {
+        let rounds = round_robin(&TEAMS[..n]);
+        let pairs: Vec<_> = rounds.iter().flatten().map(pair).collect();
+        assert_eq!(pairs.len(), n * (n - 1) / 2);
+        assert_eq!(pairs.iter().collect::<HashSet<_>>().len(), pairs.len());
+
+        for round in rounds.iter() {
+            let teams: Vec<&str> = round.iter().flat_map(|f| [f.home, f.away]).collect();
+            assert_eq!(teams.iter().collect::<HashSet<_>>().len(), teams.len());
+            assert_eq!(teams.len(), n / 2 * 2);
+        }
+    }
+}
+
+#[test]
+fn round_robin_keeps_home_games_close_to_even() {
+    let rounds = round_robin(&TEAMS[..6]);
+    let mut home: HashMap<&str, usize> = HashMap::new();
+    for f in rounds.iter().flatten() {
+        *home.entry(f.home).or_default() += 1;
+    }
+    assert!(home.values().all(|h| (2..=3).contains(h)));
+}
+
+#[test]
+fn double_round_robin_plays_each_fixture_both_ways() {
+    let rounds = double_round_robin(&TEAMS[..5]);
+    let fixtures: HashSet<_> = rounds.iter().flatten().collect();
+    assert_eq!(fixtures.len(), 20);
+    for f in fixtures.iter() {
+        assert!(fixtures.contains(&Fixture {
+            home: f.away,
+            away: f.home
+        }));
+    }
+}
+
+#[test]
+fn swiss_rounds_never_repeat_a_pairing() {
+    for n in [6, 7] {
+        let teams = &TEAMS[..n];
+        let mut matches: Vec<Match> = vec![];
+        let mut byes = vec![];
+
+        let rounds = n / 2 * 2 - 1;
+        for r in 0..rounds {
+            let standings = Standings::from_matches(matches.clone());
+            let round = swiss_round(teams, &standings)
+                .unwrap_or_else(|| panic!("{} teams: no round {} of {}", n, r + 1, rounds));
+            byes.extend(round.bye);
+            // Alphabetically earlier teams always win.
+            matches.extend(round.fixtures.iter().map(|f| {
+                f.with_outcome(if f.home < f.away {
+                    Outcome::Win
+                } else {
+                    Outcome::Loss
+                })
+            }));
+        }
+
+        let pairs: Vec<_> = matches
+            .iter()
+            .map(|m| (m.home.min(m.away), m.home.max(m.away)))
+            .collect();
+        assert_eq!(pairs.iter().collect::<HashSet<_>>().len(), pairs.len());
+        assert_eq!(byes.iter().collect::<HashSet<_>>().len(), byes.len());
+        assert_eq!(pairs.len(), rounds * (n / 2));
+    }
+}
+
+#[test]
+fn a_large_field_that_cant_be_paired_gives_up_quickly() {
+    let names: Vec<String> = (1..=33).map(|i| format!("Team {:02}", i)).collect();
+    let teams: Vec<&str> = names.iter().map(String::as_str).collect();
+    let (top, bottom) = teams.split_at(30);
+
+    // The bottom three have played everyone but each other, so with an even
+    // number of teams one of them has no one left to play.
+    let matches: Vec<Match> = bottom
+        .iter()
+        .flat_map(|b| top.iter().map(move |t| Fixture { home: t, away: b }))
+        .map(|f| f.with_outcome(Outcome::Win))
+        .collect();
+    let standings = Standings::from_matches(matches);
+
+    assert_eq!(swiss_round(&teams[1..], &standings), None);
+
+    let round = swiss_round(&teams, &standings).unwrap();
+    assert!(bottom.contains(&round.bye.unwrap()));
+    assert_eq!(round.fixtures.len(), 16);
+    assert!(round
+        .fixtures
+        .iter()
+        .all(|f| !standings.have_played(f.home, f.away)));
+}
@@ -0,0 +1,286 @@
+use tournament::*;
+
+#[test]
+fn draws_and_losses_are_counted_separately() {
+    let standings: Standings = "\
+Allegoric Alaskans;Blithering Badgers;win
+Allegoric Alaskans;Blithering Badgers;win
+Blithering Badgers;Allegoric Alaskans;draw"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        standings.get("Blithering Badgers"),
+        Some(&TeamRecord {
+            team: "Blithering Badgers".to_string(),
+            played: 3,
+            won: 0,
+            drawn: 1,
+            lost: 2,
+            points: 1,
+            goals_for: 0,
+            goals_against: 0,
+        })
+    );
+}
+
+#[test]
+fn records_are_sorted_by_points_then_name() {
+    let standings: Standings = "\
+Devastating Donkeys;Courageous Californians;draw
+Blithering Badgers;Allegoric Alaskans;win"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        teams(&standings),
+        [
+            "Blithering Badgers",
+            "Courageous Californians",
+            "Devastating Donkeys",
+            "Allegoric Alaskans",
+        ]
+    );
+}
+
+#[test]
+fn bad_lines_are_errors() {
+    let e = "\
+Allegoric Alaskans;Blithering Badgers;won
+Allegoric Alaskans;Blithering Badgers;win
+Allegoric Alaskans;Blithering Badgers;win;draw"
+        .parse::<Standings>()
+        .unwrap_err();
+
+    assert_eq!(e.0.iter().map(|e| e.line).collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(e.0[1].error, MatchError::FieldCount(4));
+}
+
+fn teams(standings: &Standings) -> Vec<&str> {
+    standings
+        .records()
+        .iter()
+        .map(|r| r.team.as_str())
+        .collect()
+}
+
+#[test]
+fn head_to_head_breaks_ties_before_names() {
+    let input = "\
+Allegoric Alaskans;Courageous Californians;win
+Blithering Badgers;Allegoric Alaskans;win
+Devastating Donkeys;Courageous Californians;draw";
+    let rules = LeagueRules {
+        tiebreaks: vec![Tiebreak::HeadToHead],
+        ..Default::default()
+    };
+
+    let standings = Standings::parse_with(input, &rules).unwrap();
+    assert_eq!(
+        &teams(&standings)[..2],
+        ["Blithering Badgers", "Allegoric Alaskans"]
+    );
+    let standings: Standings = input.parse().unwrap();
+    assert_eq!(
+        &teams(&standings)[..2],
+        ["Allegoric Alaskans", "Blithering Badgers"]
+    );
+}
+
+#[test]
+fn tiebreaks_apply_in_order() {
+    let input = "\
+Allegoric Alaskans;Courageous Californians;draw
+Allegoric Alaskans;Devastating Donkeys;draw
+Blithering Badgers;Courageous Californians;win
+Blithering Badgers;Devastating Donkeys;loss";
+    let rules = LeagueRules {
+        points: PointsTable::TWO_FOR_A_WIN,
+        bonuses: vec![],
+        tiebreaks: vec![Tiebreak::HeadToHead, Tiebreak::Wins, Tiebreak::Name],
+    };
+
+    let standings = Standings::parse_with(input, &rules).unwrap();
+    assert_eq!(
+        teams(&standings),
+        [
+            "Devastating Donkeys",
+            "Blithering Badgers",
+            "Allegoric Alaskans",
+            "Courageous Californians",
+        ]
+    );
+    assert_eq!(standings.records()[1].points, 2);
+    assert_eq!(standings.records()[2].points, 2);
+}
+
+#[test]
+fn bonus_points_count_towards_the_total() {
+    let rules = LeagueRules {
+        bonuses: vec![Bonus::AwayWin(1), Bonus::AwayDraw(1)],
+        ..Default::default()
+    };
+    let standings = Standings::parse_with(
+        "\
+Allegoric Alaskans;Blithering Badgers;loss
+Allegoric Alaskans;Blithering Badgers;draw",
+        &rules,
+    )
+    .unwrap();
+
+    assert_eq!(standings.get("Blithering Badgers").unwrap().points, 6);
+    assert_eq!(standings.get("Allegoric Alaskans").unwrap().points, 1);
+}
+
+#[test]
+fn scores_decide_the_outcome_and_count_goals() {
+    let standings: Standings = "\
+Allegoric Alaskans;Blithering Badgers;3-1
+Blithering Badgers;Allegoric Alaskans;2-2
+Blithering Badgers;Courageous Californians;win"
+        .parse()
+        .unwrap();
+
+    let alaskans = standings.get("Allegoric Alaskans").unwrap();
+    assert_eq!((alaskans.won, alaskans.drawn, alaskans.points), (1, 1, 4));
+    assert_eq!((alaskans.goals_for, alaskans.goals_against), (5, 3));
+    assert_eq!(alaskans.goal_difference(), 2);
+
+    let badgers = standings.get("Blithering Badgers").unwrap();
+    assert_eq!((badgers.won, badgers.drawn, badgers.lost), (1, 1, 1));
+    assert_eq!(badgers.goal_difference(), -2);
+}
+
+#[test]
+fn goal_difference_breaks_ties() {
+    let input = "\
+Allegoric Alaskans;Courageous Californians;1-0
+Blithering Badgers;Devastating Donkeys;4-0";
+    let rules = LeagueRules {
+        tiebreaks: vec![Tiebreak::GoalDifference],
+        ..Default::default()
+    };
+
+    let standings = Standings::parse_with(input, &rules).unwrap();
+    assert_eq!(
+        teams(&standings),
+        [
+            "Blithering Badgers",
+            "Allegoric Alaskans",
+            "Courageous Californians",
+            "Devastating Donkeys",
+        ]
+    );
+}
+
+#[test]
+fn adding_matches_one_at_a_time_agrees_with_tallying_them_all() {
+    let input = "\
+Allegoric Alaskans;Blithering Badgers;3-1
+Devastating Donkeys;Courageous Californians;draw
+Devastating Donkeys;Allegoric Alaskans;win
+Courageous Californians;Blithering Badgers;0-2
+Blithering Badgers;Devastating Donkeys;loss
+Allegoric Alaskans;Courageous Californians;win";
+    let rules = LeagueRules {
+        tiebreaks: vec![Tiebreak::HeadToHead, Tiebreak::GoalDifference],
+        ..Default::default()
+    };
+    let matches = parse_matches(input).strict().unwrap();
+
+    let mut standings = Standings::new(rules.clone());
+    for (i, m) in matches.iter().enumerate() {
+        standings.add(m);
+        assert_eq!(
+            standings,
+            Standings::from_matches_with(matches[..=i].iter().copied(), &rules)
+        );
+    }
+}
+
+#[test]
+fn retracting_a_match_undoes_it() {
+    let matches = parse_matches(
+        "\
+Allegoric Alaskans;Blithering Badgers;3-1
+Blithering Badgers;Courageous Californians;draw",
+    )
+    .strict()
+    .unwrap();
+    let before = Standings::from_matches(matches.iter().copied());
+
+    let mistake = Match::try_from("Courageous Californians;Allegoric Alaskans;2-0").unwrap();
+    let mut standings = before.clone();
+    standings.add(&mistake);
+    assert_eq!(standings.records()[0].team, "Courageous Californians");
+    standings.retract(&mistake).unwrap();
+    assert_eq!(standings, before);
+}
+
+#[test]
+fn matches_that_were_not_counted_cannot_be_retracted() {
+    let mut standings: Standings = "Allegoric Alaskans;Blithering Badgers;3-1".parse().unwrap();
+    let before = standings.clone();
+
+    for wrong in [
+        "Allegoric Alaskans;Blithering Badgers;loss",
+        "Allegoric Alaskans;Blithering Badgers;4-1",
+        "Blithering Badgers;Courageous Californians;draw",
+    ] {
+        let m = Match::try_from(wrong).unwrap();
+        assert_eq!(
+            standings.retract(&m),
+            Err(NotRecorded {
+                home: m.home.to_string(),
+                away: m.away.to_string()
+            })
+        );
+        assert_eq!(standings, before);
+    }
+}
+
+#[test]
+fn a_retraction_must_have_the_score_that_was_counted() {
+    let mut standings: Standings = "\
+Allegoric Alaskans;Blithering Badgers;3-1
+Allegoric Alaskans;Courageous Californians;win"
+        .parse()
+        .unwrap();
+    let before = standings.clone();
+
+    let wrong = Match::try_from("Allegoric Alaskans;Blithering Badgers;1-0").unwrap();
+    assert!(standings.retract(&wrong).is_err());
+    let no_score = Match::try_from("Allegoric Alaskans;Blithering Badgers;win").unwrap();
+    assert!(standings.retract(&no_score).is_err());
+    assert_eq!(standings, before);
+
+    let right = Match::try_from("Allegoric Alaskans;Blithering Badgers;3-1").unwrap();
+    standings.retract(&right).unwrap();
+    let alaskans = standings.get("Allegoric Alaskans").unwrap();
+    assert_eq!((alaskans.goals_for, alaskans.goals_against), (0, 0));
+}
+
+#[test]
+fn a_retraction_must_have_the_venue_that_was_counted() {
+    let mut standings: Standings = "\
+Allegoric Alaskans;Blithering Badgers;win
+Blithering Badgers;Allegoric Alaskans;win"
+        .parse()
+        .unwrap();
+    let before = standings.clone();
+
+    let never_played = Match::try_from("Allegoric Alaskans;Blithering Badgers;loss").unwrap();
+    assert_eq!(
+        standings.retract(&never_played),
+        Err(NotRecorded {
+            home: "Allegoric Alaskans".to_string(),
+            away: "Blithering Badgers".to_string(),
+        })
+    );
+    assert_eq!(standings, before);
+
+    let return_leg = Match::try_from("Blithering Badgers;Allegoric Alaskans;win").unwrap();
+    standings.retract(&return_leg).unwrap();
+    assert!(standings.retract(&return_leg).is_err());
+    assert_eq!(standings.get("Allegoric Alaskans").unwrap().won, 1);
+}
@@ -0,0 +1,93 @@
+use std::io::{self, BufReader, Cursor, Read};
+
+use tournament::*;
+
+const SEASON: &str = "\
+Allegoric Alaskans;Blithering Badgers;3-1
+Devastating Donkeys;Courageous Californians;draw
+Devastating Donkeys;Allegoric Alaskans;win
+Courageous Californians;Blithering Badgers;0-2
+Blithering Badgers;Devastating Donkeys;loss
+Allegoric Alaskans;Courageous Californians;win";
+
+#[test]
+fn reading_agrees_with_parsing() {
+    let rules = LeagueRules {
+        tiebreaks: vec![Tiebreak::GoalDifference],
+        ..Default::default()
+    };
+    assert_eq!(
+        Standings::read_with(Cursor::new(SEASON), &rules).unwrap(),
+        Standings::parse_with(SEASON, &rules).unwrap()
+    );
+}
+
+#[test]
+fn lines_can_be_longer_than_the_readers_buffer() {
+    let reader = BufReader::with_capacity(8, SEASON.as_bytes());
+    assert_eq!(Standings::read(reader).unwrap().to_string(), tally(SEASON));
+}
+
+#[test]
+fn every_bad_line_is_reported() {
+    let input = "\
+Allegoric Alaskans;Blithering Badgers;win
+
+Blithering Badgers
+Blithering Badgers;Courageous Californians;lost
+";
+    let Err(ReadError::Invalid(InvalidLines(errors))) = Standings::read(Cursor::new(input)) else {
+        panic!("expected bad lines");
+    };
+    let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, [3, 4]);
+    assert_eq!(
+        errors[1].text,
+        "Blithering Badgers;Courageous Californians;lost"
+    );
+}
+
+/// Reads `input`, then fails.
+struct Broken<'a>(&'a [u8]);
+
+impl Read for Broken<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+            return Err(io::Error::other("disk on fire"));
+        }
+        self.0.read(buf)
+    }
+}
+
+#[test]
+fn read_errors_stop_the_tally() {
+    let reader = BufReader::new(Broken(b"Allegoric Alaskans;Blithering Badgers;win\n"));
+    let mut counted = 0;
+    let error = read_matches(reader, |_| counted += 1).unwrap_err();
+    assert_eq!(error.to_string(), "disk on fire");
+    assert_eq!(counted, 1);
+
+    let reader = BufReader::new(Broken(b""));
+    let error = Standings::read(reader).unwrap_err();
+    assert!(matches!(error, ReadError::Io(_)));
+    assert_eq!(error.to_string(), "couldn't read matches: disk on fire");
+}
+
+#[test]
+fn a_long_archive_is_read_a_line_at_a_time() {
+    let teams = [
+        "Allegoric Alaskans",
+        "Blithering Badgers",
+        "Courageous Californians",
+    ];
+    let lines = (0..30_000).map(move |i| {
+        let home = teams[i % 3];
+        let away = teams[(i + 1) % 3];
+        format!("{};{};{}-{}\n", home, away, i % 4, i % 3)
+    });
+    let archive = lines.flat_map(String::into_bytes).collect::<Vec<u8>>();
+
+    let standings = Standings::read(archive.as_slice()).unwrap();
+    assert_eq!(standings.records().len(), 3);
+    assert!(standings.records().iter().all(|r| r.played == 20_000));
+}